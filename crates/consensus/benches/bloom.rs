@@ -0,0 +1,41 @@
+#![allow(missing_docs)]
+
+use alloy_consensus::Receipt;
+use alloy_primitives::{Address, Log, LogData, B256};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// Builds a receipt with `n` logs spread over `shapes` distinct (address, topics) pairs.
+fn receipt(n: usize, shapes: usize) -> Receipt {
+    let logs = (0..n)
+        .map(|i| {
+            let shape = (i % shapes) as u64;
+            Log {
+                address: Address::with_last_byte(shape as u8),
+                data: LogData::new_unchecked(
+                    vec![B256::repeat_byte(0xdd), B256::left_padding_from(&shape.to_be_bytes())],
+                    Default::default(),
+                ),
+            }
+        })
+        .collect();
+    Receipt { status: true.into(), cumulative_gas_used: 0, logs }
+}
+
+fn bloom(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bloom");
+    for (n, shapes) in [(100, 4), (1_000, 4), (1_000, 100), (1_000, 1_000)] {
+        let receipt = receipt(n, shapes);
+        let id = format!("{n}_logs/{shapes}_shapes");
+        group.bench_with_input(BenchmarkId::new("slow", &id), &receipt, |b, receipt| {
+            b.iter(|| receipt.bloom_slow())
+        });
+        #[cfg(feature = "bloom-cache")]
+        group.bench_with_input(BenchmarkId::new("cached", &id), &receipt, |b, receipt| {
+            b.iter(|| receipt.bloom_cached())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bloom);
+criterion_main!(benches);
@@ -0,0 +1,64 @@
+#![allow(missing_docs)]
+
+use alloy_consensus::{Receipt, Receipts};
+use alloy_primitives::{Address, Log, LogData, B256, U256};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rayon::iter::ParallelIterator;
+
+/// Builds a receipt with `n` ERC-20 `Transfer` logs.
+fn transfers(n: usize) -> Receipt {
+    let signature = B256::repeat_byte(0xdd);
+    let logs = (0..n)
+        .map(|i| Log {
+            address: Address::repeat_byte(i as u8),
+            data: LogData::new_unchecked(
+                vec![signature, Address::repeat_byte(1).into_word(), B256::with_last_byte(i as u8)],
+                U256::from(i).to_be_bytes_vec().into(),
+            ),
+        })
+        .collect();
+    Receipt { status: true.into(), cumulative_gas_used: 0, logs }
+}
+
+/// Decodes the sender, recipient and value of a `Transfer` log.
+fn decode_transfer(log: &Log) -> (Address, Address, U256) {
+    let topics = log.topics();
+    (
+        Address::from_word(topics[1]),
+        Address::from_word(topics[2]),
+        U256::from_be_slice(&log.data.data),
+    )
+}
+
+fn logs_par(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decode_logs");
+    for n in [100, 1_000, 10_000, 100_000] {
+        let receipt = transfers(n);
+        group.bench_with_input(BenchmarkId::new("serial", n), &receipt, |b, receipt| {
+            b.iter(|| receipt.logs.iter().map(decode_transfer).collect::<Vec<_>>())
+        });
+        group.bench_with_input(BenchmarkId::new("parallel", n), &receipt, |b, receipt| {
+            b.iter(|| receipt.logs_par().map(decode_transfer).collect::<Vec<_>>())
+        });
+    }
+    group.finish();
+}
+
+fn summary_par(c: &mut Criterion) {
+    let mut group = c.benchmark_group("summary");
+    for blocks in [10, 100, 1_000] {
+        let receipts = Receipts {
+            receipt_vec: (0..blocks).map(|_| (0..200).map(|_| transfers(4)).collect()).collect(),
+        };
+        group.bench_with_input(BenchmarkId::new("serial", blocks), &receipts, |b, receipts| {
+            b.iter(|| receipts.summary())
+        });
+        group.bench_with_input(BenchmarkId::new("parallel", blocks), &receipts, |b, receipts| {
+            b.iter(|| receipts.summary_par())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, logs_par, summary_par);
+criterion_main!(benches);
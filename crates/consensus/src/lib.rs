@@ -20,8 +20,21 @@ pub use constants::{EMPTY_OMMER_ROOT_HASH, EMPTY_ROOT_HASH};
 
 mod receipt;
 pub use receipt::{
-    Eip658Value, Receipt, ReceiptEnvelope, ReceiptWithBloom, Receipts, RlpReceipt, TxReceipt,
+    assemble_from_counts, bloom_xor, encode_with_toc, gas_divergence, join_from_storage, read_at,
+    sort_by_tx_index, split_for_storage, strip_tx_indices, validate_block_gas_limit,
+    validate_header_bloom, verify_order, AssembleError, BlockReceiptsIndex, Eip658Value,
+    Eip7702Receipt, GasDivergence, IndexedReceipts, LogFilter, LogOnlyReceipt, LogOrder, LogPool,
+    MigrationError, OpReceipt, Receipt, ReceiptEnvelope, ReceiptError, ReceiptHeader, ReceiptRow,
+    ReceiptRowError, ReceiptWithBloom, Receipts, ReceiptsArc, ReceiptsSummary, RlpReceipt, RpcLog,
+    SparseReceipts, SparseReceiptsError, SplitReceiptsError, TxReceipt, VersionedReceipts,
+    MAX_EMPTY_BLOCK_FILL,
 };
+#[cfg(feature = "snappy")]
+pub use receipt::{
+    decode_snappy_receipts, encode_snappy_receipts, SnappyReceiptsError, MAX_SNAPPY_RECEIPTS_LEN,
+};
+#[cfg(feature = "serde")]
+pub use receipt::{deserialize_rpc_receipt, ReceiptSerializeOptions};
 
 pub mod proofs;
 
@@ -1,10 +1,17 @@
 //! Helper function for calculating Merkle proofs and hashes.
 
-use crate::{Header, EMPTY_OMMER_ROOT_HASH};
-use alloc::vec::Vec;
+use crate::{
+    Header, ReceiptWithBloom, Receipts, RlpReceipt, EMPTY_OMMER_ROOT_HASH, EMPTY_ROOT_HASH,
+};
+use alloc::{vec, vec::Vec};
 use alloy_eips::{eip2718::Encodable2718, eip4895::Withdrawal};
 use alloy_primitives::{keccak256, B256};
-use alloy_trie::root::{ordered_trie_root, ordered_trie_root_with_encoder};
+use alloy_rlp::Encodable;
+use alloy_trie::{
+    root::{adjust_index_for_rlp, ordered_trie_root, ordered_trie_root_with_encoder},
+    HashBuilder, Nibbles,
+};
+use core::fmt;
 
 /// Calculate a transaction root.
 ///
@@ -16,6 +23,177 @@ where
     ordered_trie_root_with_encoder(transactions, |tx: &T, buf| tx.encode_2718(buf))
 }
 
+/// Calculates the receipt root for the given receipts.
+pub fn calculate_receipt_root<R: RlpReceipt>(receipts: &[ReceiptWithBloom<R>]) -> B256 {
+    ordered_trie_root(receipts)
+}
+
+/// Calculates the receipt root from already RLP encoded receipts, e.g. obtained through
+/// [`ReceiptWithBloom::encode_and_cache`].
+pub fn calculate_receipt_root_from_encoded<T: AsRef<[u8]>>(encoded: &[T]) -> B256 {
+    ordered_trie_root_with_encoder(encoded, |receipt, buf| buf.extend_from_slice(receipt.as_ref()))
+}
+
+/// Calculates the receipt root, using `buf` as scratch space for the receipt encodings.
+fn calculate_receipt_root_with_buf<R: RlpReceipt>(
+    receipts: &[ReceiptWithBloom<R>],
+    buf: &mut Vec<u8>,
+) -> B256 {
+    receipt_root_inner(receipts, buf, None)
+}
+
+/// Calculates the receipt root along with the keccak256 hash of each receipt's encoding, in the
+/// order of the receipts.
+///
+/// Each receipt is encoded only once for both the trie and its hash.
+pub fn root_and_leaves<R: RlpReceipt>(receipts: &[ReceiptWithBloom<R>]) -> (B256, Vec<B256>) {
+    let mut leaves = vec![B256::ZERO; receipts.len()];
+    let root = receipt_root_inner(receipts, &mut Vec::new(), Some(&mut leaves));
+    (root, leaves)
+}
+
+/// Builds the receipt trie, encoding each receipt into `buf` and, if `leaves` is given, storing
+/// the keccak256 hash of the encoding at the receipt's index.
+fn receipt_root_inner<R: RlpReceipt>(
+    receipts: &[ReceiptWithBloom<R>],
+    buf: &mut Vec<u8>,
+    mut leaves: Option<&mut [B256]>,
+) -> B256 {
+    if receipts.is_empty() {
+        return EMPTY_ROOT_HASH;
+    }
+
+    let mut hb = HashBuilder::default();
+    for i in 0..receipts.len() {
+        let index = adjust_index_for_rlp(i, receipts.len());
+        buf.clear();
+        receipts[index].encode(buf);
+        if let Some(leaves) = leaves.as_deref_mut() {
+            leaves[index] = keccak256(&*buf);
+        }
+        hb.add_leaf(Nibbles::unpack(alloy_rlp::encode_fixed_size(&index)), buf);
+    }
+    hb.root()
+}
+
+/// Validates the receipt roots of blocks as they are streamed in, one block at a time.
+///
+/// Receipts are only borrowed for the duration of [`StreamingRootValidator::push_block`], so the
+/// caller does not need to hold on to all blocks to validate a range. The encoding buffer is
+/// reused across blocks.
+#[derive(Debug, Default)]
+pub struct StreamingRootValidator {
+    /// Number of blocks validated so far.
+    blocks: u64,
+    /// Scratch buffer for receipt encodings.
+    buf: Vec<u8>,
+}
+
+impl StreamingRootValidator {
+    /// Creates a new validator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of blocks successfully validated so far.
+    pub const fn blocks_validated(&self) -> u64 {
+        self.blocks
+    }
+
+    /// Computes the receipt root of the next block and compares it with `expected_root`.
+    ///
+    /// On mismatch, the returned error contains the position of the block in the stream. A
+    /// mismatching block is not counted, so it can be retried.
+    pub fn push_block<R: RlpReceipt>(
+        &mut self,
+        receipts: &[ReceiptWithBloom<R>],
+        expected_root: B256,
+    ) -> Result<(), RootMismatch> {
+        let root = calculate_receipt_root_with_buf(receipts, &mut self.buf);
+        if root != expected_root {
+            return Err(RootMismatch { block: self.blocks, got: root, expected: expected_root });
+        }
+        self.blocks += 1;
+        Ok(())
+    }
+}
+
+/// Error returned by [`StreamingRootValidator::push_block`] when a block's receipt root does not
+/// match the expected one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RootMismatch {
+    /// The position of the mismatching block in the stream.
+    pub block: u64,
+    /// The computed receipt root.
+    pub got: B256,
+    /// The expected receipt root.
+    pub expected: B256,
+}
+
+impl fmt::Display for RootMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "receipt root mismatch at block {}: got {}, expected {}",
+            self.block, self.got, self.expected
+        )
+    }
+}
+
+impl core::error::Error for RootMismatch {}
+
+/// Validates the receipt roots of all blocks of `receipts` against `expected`, in order.
+///
+/// The encoding buffer is reused across blocks. Returns the first mismatching block, or an error
+/// if the number of blocks and expected roots differ.
+pub fn validate_roots<R: RlpReceipt>(
+    receipts: &Receipts<ReceiptWithBloom<R>>,
+    expected: &[B256],
+) -> Result<(), ValidateRootsError> {
+    if receipts.len() != expected.len() {
+        return Err(ValidateRootsError::Length { blocks: receipts.len(), roots: expected.len() });
+    }
+
+    let mut validator = StreamingRootValidator::new();
+    for (block, &root) in receipts.receipt_vec.iter().zip(expected) {
+        validator.push_block(block, root)?;
+    }
+    Ok(())
+}
+
+/// Error returned by [`validate_roots`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValidateRootsError {
+    /// The number of blocks differs from the number of expected roots.
+    Length {
+        /// The number of blocks.
+        blocks: usize,
+        /// The number of expected roots.
+        roots: usize,
+    },
+    /// The receipt root of a block does not match the expected one.
+    Mismatch(RootMismatch),
+}
+
+impl From<RootMismatch> for ValidateRootsError {
+    fn from(mismatch: RootMismatch) -> Self {
+        Self::Mismatch(mismatch)
+    }
+}
+
+impl fmt::Display for ValidateRootsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Length { blocks, roots } => {
+                write!(f, "expected {roots} receipt roots, got {blocks} blocks")
+            }
+            Self::Mismatch(mismatch) => mismatch.fmt(f),
+        }
+    }
+}
+
+impl core::error::Error for ValidateRootsError {}
+
 /// Calculates the root hash of the withdrawals.
 pub fn calculate_withdrawals_root(withdrawals: &[Withdrawal]) -> B256 {
     ordered_trie_root(withdrawals)
@@ -32,3 +210,85 @@ pub fn calculate_ommers_root(ommers: &[Header]) -> B256 {
     alloy_rlp::encode_list(ommers, &mut ommers_rlp);
     keccak256(ommers_rlp)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Receipt;
+    use alloy_primitives::{Address, Log, LogData};
+
+    #[test]
+    fn streaming_root_validator() {
+        let receipts: Vec<_> = (1..=3)
+            .map(|i| {
+                Receipt {
+                    status: true.into(),
+                    cumulative_gas_used: 21000 * i,
+                    logs: vec![Log {
+                        address: Address::repeat_byte(i as u8),
+                        data: LogData::new_unchecked(vec![], Default::default()),
+                    }],
+                }
+                .with_bloom()
+            })
+            .collect();
+        let root = calculate_receipt_root(&receipts);
+
+        let mut validator = StreamingRootValidator::new();
+        validator.push_block(&receipts, root).unwrap();
+        validator.push_block::<Receipt>(&[], EMPTY_ROOT_HASH).unwrap();
+        assert_eq!(
+            validator.push_block(&receipts[..2], root),
+            Err(RootMismatch {
+                block: 2,
+                got: calculate_receipt_root(&receipts[..2]),
+                expected: root
+            })
+        );
+        assert_eq!(validator.blocks_validated(), 2);
+
+        let encoded: Vec<_> = receipts.iter().map(|r| r.encode_and_cache().0).collect();
+        assert_eq!(calculate_receipt_root_from_encoded(&encoded), root);
+    }
+
+    #[test]
+    fn validate_roots() {
+        let receipt = |i: u128| Receipt::<Log> { cumulative_gas_used: i, ..Default::default() };
+        let blocks = vec![vec![receipt(1).with_bloom()], vec![], vec![receipt(2).with_bloom()]];
+        let roots: Vec<_> = blocks.iter().map(|block| calculate_receipt_root(block)).collect();
+        let receipts = Receipts { receipt_vec: blocks };
+
+        assert_eq!(super::validate_roots(&receipts, &roots), Ok(()));
+        assert_eq!(
+            super::validate_roots(&receipts, &roots[..2]),
+            Err(ValidateRootsError::Length { blocks: 3, roots: 2 })
+        );
+
+        let mut wrong = roots;
+        wrong[1] = B256::ZERO;
+        assert_eq!(
+            super::validate_roots(&receipts, &wrong),
+            Err(ValidateRootsError::Mismatch(RootMismatch {
+                block: 1,
+                got: EMPTY_ROOT_HASH,
+                expected: B256::ZERO
+            }))
+        );
+    }
+
+    #[test]
+    fn root_and_leaves() {
+        let receipts: Vec<_> = (0..130u128)
+            .map(|i| Receipt::<Log> { cumulative_gas_used: i, ..Default::default() }.with_bloom())
+            .collect();
+
+        for len in [0, 1, 2, 127, 128, 130] {
+            let (root, leaves) = super::root_and_leaves(&receipts[..len]);
+            assert_eq!(root, calculate_receipt_root(&receipts[..len]));
+            assert_eq!(leaves.len(), len);
+            for (receipt, leaf) in receipts.iter().zip(leaves) {
+                assert_eq!(leaf, keccak256(alloy_rlp::encode(receipt)));
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use crate::receipt::{Eip658Value, Receipt, ReceiptWithBloom, RlpReceipt, TxReceipt};
+use alloc::vec::Vec;
+use alloy_primitives::{Bloom, Log};
+use alloy_rlp::{BufMut, Decodable, Encodable};
+
+/// A receipt extended with the outcome of each authorization of an [EIP-7702] transaction.
+///
+/// In RLP, the authorization results are appended after the fields of the inner receipt as a list
+/// of booleans, and only when present. A receipt without them therefore has the same encoding as
+/// the inner receipt, and inner receipts decode as an [`Eip7702Receipt`] without authorization
+/// results.
+///
+/// [EIP-7702]: https://eips.ethereum.org/EIPS/eip-7702
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct Eip7702Receipt<R = Receipt<Log>> {
+    /// The base receipt.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub inner: R,
+    /// Whether each authorization of the transaction was applied, in the order of the
+    /// authorization list.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub auth_results: Option<Vec<bool>>,
+}
+
+impl<R> Eip7702Receipt<R> {
+    /// Creates a new [`Eip7702Receipt`] without authorization results.
+    pub const fn new(inner: R) -> Self {
+        Self { inner, auth_results: None }
+    }
+
+    /// Consumes the receipt, returning the base receipt.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R> From<R> for Eip7702Receipt<R> {
+    fn from(inner: R) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl<R: TxReceipt> TxReceipt for Eip7702Receipt<R> {
+    type Log = R::Log;
+
+    fn status_or_post_state(&self) -> Eip658Value {
+        self.inner.status_or_post_state()
+    }
+
+    fn status(&self) -> bool {
+        self.inner.status()
+    }
+
+    fn bloom(&self) -> Bloom {
+        self.inner.bloom()
+    }
+
+    fn bloom_cheap(&self) -> Option<Bloom> {
+        self.inner.bloom_cheap()
+    }
+
+    fn cumulative_gas_used(&self) -> u128 {
+        self.inner.cumulative_gas_used()
+    }
+
+    fn logs(&self) -> &[Self::Log] {
+        self.inner.logs()
+    }
+}
+
+impl<R: RlpReceipt> RlpReceipt for Eip7702Receipt<R> {
+    fn rlp_encoded_fields_length_with_bloom(&self, bloom: Bloom) -> usize {
+        self.inner.rlp_encoded_fields_length_with_bloom(bloom)
+            + self.auth_results.as_ref().map_or(0, |results| results.length())
+    }
+
+    fn rlp_encode_fields_with_bloom(&self, bloom: Bloom, out: &mut dyn BufMut) {
+        self.inner.rlp_encode_fields_with_bloom(bloom, out);
+        if let Some(ref auth_results) = self.auth_results {
+            auth_results.encode(out);
+        }
+    }
+
+    fn rlp_decode_fields_with_bloom(buf: &mut &[u8]) -> alloy_rlp::Result<ReceiptWithBloom<Self>> {
+        let ReceiptWithBloom { receipt, logs_bloom } = R::rlp_decode_fields_with_bloom(buf)?;
+        let mut this = Self::new(receipt);
+
+        if !buf.is_empty() {
+            this.auth_results = Some(Decodable::decode(buf)?);
+        }
+
+        Ok(ReceiptWithBloom { receipt: this, logs_bloom })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rlp_roundtrip() {
+        let inner = Receipt::<Log> { cumulative_gas_used: 46_000, ..Default::default() };
+        let receipt = ReceiptWithBloom::from(Eip7702Receipt {
+            inner: inner.clone(),
+            auth_results: Some(vec![true, false]),
+        });
+        let data = alloy_rlp::encode(&receipt);
+        assert_eq!(alloy_rlp::Decodable::decode(&mut &data[..]), Ok(receipt));
+
+        // Receipts without authorization results are encoded like the base receipt.
+        let data = alloy_rlp::encode(inner.clone().with_bloom());
+        assert_eq!(
+            alloy_rlp::Decodable::decode(&mut &data[..]),
+            Ok(ReceiptWithBloom::from(Eip7702Receipt::new(inner)))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_auth_results() {
+        let json =
+            r#"{"status":"0x1","cumulativeGasUsed":"0xb3b0","logs":[],"authResults":[true,false]}"#;
+        let receipt: Eip7702Receipt<Receipt<()>> = serde_json::from_str(json).unwrap();
+        assert_eq!(receipt.auth_results, Some(vec![true, false]));
+        assert_eq!(serde_json::to_string(&receipt).unwrap(), json);
+
+        let json = r#"{"status":"0x1","cumulativeGasUsed":"0xb3b0","logs":[]}"#;
+        let receipt: Eip7702Receipt<Receipt<()>> = serde_json::from_str(json).unwrap();
+        assert_eq!(receipt.auth_results, None);
+        assert_eq!(serde_json::to_string(&receipt).unwrap(), json);
+    }
+}
@@ -0,0 +1,63 @@
+use alloc::vec::Vec;
+use alloy_primitives::{Address, Bloom, BloomInput, Log, B256};
+
+/// A filter matching logs by their emitting address and topics.
+///
+/// An empty address list matches any address. Each of the four topic positions matches any topic
+/// if empty, or any of the given topics otherwise.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LogFilter {
+    /// The addresses to match.
+    pub addresses: Vec<Address>,
+    /// The topics to match, by position.
+    pub topics: [Vec<B256>; 4],
+}
+
+impl LogFilter {
+    /// Creates a new filter matching every log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an address to match.
+    pub fn address(mut self, address: Address) -> Self {
+        self.addresses.push(address);
+        self
+    }
+
+    /// Adds an event signature to match, i.e. a topic at position 0.
+    pub fn event_signature(self, signature: B256) -> Self {
+        self.topic(0, signature)
+    }
+
+    /// Adds a topic to match at the given position.
+    ///
+    /// # Panics
+    ///
+    /// If `position` is greater than 3.
+    pub fn topic(mut self, position: usize, topic: B256) -> Self {
+        self.topics[position].push(topic);
+        self
+    }
+
+    /// Returns `true` if the log matches the filter.
+    pub fn matches(&self, log: &Log) -> bool {
+        if !self.addresses.is_empty() && !self.addresses.contains(&log.address) {
+            return false;
+        }
+        self.topics.iter().enumerate().all(|(i, topics)| {
+            topics.is_empty() || log.topics().get(i).is_some_and(|topic| topics.contains(topic))
+        })
+    }
+
+    /// Returns `true` if the bloom may contain a log matching the filter.
+    ///
+    /// Like any bloom filter check, this can yield false positives but no false negatives.
+    pub fn matches_bloom(&self, bloom: &Bloom) -> bool {
+        let contains = |input: &[u8]| bloom.contains_input(BloomInput::Raw(input));
+        (self.addresses.is_empty() || self.addresses.iter().any(|a| contains(a.as_slice())))
+            && self.topics.iter().all(|topics| {
+                topics.is_empty() || topics.iter().any(|topic| contains(topic.as_slice()))
+            })
+    }
+}
@@ -0,0 +1,88 @@
+use crate::receipt::{receipts::aggregate_bloom, TxReceipt};
+use alloc::vec::Vec;
+use alloy_primitives::Bloom;
+
+/// Receipts of a sparse, possibly non-contiguous set of blocks, keyed by block number.
+///
+/// Unlike [`Receipts`](crate::Receipts), which is addressed by position, blocks are looked up by
+/// their number via binary search. The bloom filter of each block is computed once on
+/// construction.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BlockReceiptsIndex<T> {
+    numbers: Vec<u64>,
+    receipts: Vec<Vec<T>>,
+    blooms: Vec<Bloom>,
+}
+
+impl<T: TxReceipt> BlockReceiptsIndex<T> {
+    /// Builds the index from `(block_number, receipts)` pairs given in any order.
+    ///
+    /// Returns `None` if a block number occurs more than once.
+    pub fn new(mut blocks: Vec<(u64, Vec<T>)>) -> Option<Self> {
+        blocks.sort_unstable_by_key(|(number, _)| *number);
+        if blocks.windows(2).any(|w| w[0].0 == w[1].0) {
+            return None;
+        }
+
+        let blooms = blocks.iter().map(|(_, receipts)| aggregate_bloom(receipts)).collect();
+        let (numbers, receipts) = blocks.into_iter().unzip();
+        Some(Self { numbers, receipts, blooms })
+    }
+}
+
+impl<T> BlockReceiptsIndex<T> {
+    /// Returns the number of indexed blocks.
+    pub fn len(&self) -> usize {
+        self.numbers.len()
+    }
+
+    /// Returns `true` if no blocks are indexed.
+    pub fn is_empty(&self) -> bool {
+        self.numbers.is_empty()
+    }
+
+    /// Returns the indexed block numbers in ascending order.
+    pub fn block_numbers(&self) -> &[u64] {
+        &self.numbers
+    }
+
+    /// Returns the receipts of the block with the given number, if it is indexed.
+    pub fn receipts_for_block(&self, number: u64) -> Option<&[T]> {
+        self.position(number).map(|i| self.receipts[i].as_slice())
+    }
+
+    /// Returns the aggregated bloom filter of the block with the given number, if it is indexed.
+    pub fn bloom_for_block(&self, number: u64) -> Option<Bloom> {
+        self.position(number).map(|i| self.blooms[i])
+    }
+
+    fn position(&self, number: u64) -> Option<usize> {
+        self.numbers.binary_search(&number).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::receipt::test_utils::{log, receipt};
+
+    #[test]
+    fn lookup_by_number() {
+        let with_log = |byte| receipt(vec![log(byte)]);
+        let index = BlockReceiptsIndex::new(vec![
+            (100, vec![with_log(2)]),
+            (7, vec![with_log(1)]),
+            (42, vec![]),
+        ])
+        .unwrap();
+
+        assert_eq!(index.block_numbers(), &[7, 42, 100]);
+        assert_eq!(index.receipts_for_block(100), Some(&[with_log(2)][..]));
+        assert_eq!(index.receipts_for_block(42), Some(&[][..]));
+        assert_eq!(index.receipts_for_block(8), None);
+        assert_eq!(index.bloom_for_block(7), Some(with_log(1).bloom_slow()));
+        assert_eq!(index.bloom_for_block(42), Some(Bloom::ZERO));
+
+        assert!(BlockReceiptsIndex::new(vec![(1, vec![with_log(1)]), (1, vec![])]).is_none());
+    }
+}
@@ -0,0 +1,106 @@
+use crate::receipt::{receipts::aggregate_bloom, LogFilter, Receipts, TxReceipt};
+use alloc::vec::Vec;
+use alloy_primitives::Bloom;
+
+/// A [`Receipts`] collection that maintains the aggregated bloom filter of each block as blocks
+/// are appended.
+///
+/// The bloom of a block is computed once in [`IndexedReceipts::push_block`], so append-heavy
+/// indexers can run bloom-prefiltered queries without recomputing blooms.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IndexedReceipts<T> {
+    receipts: Receipts<T>,
+    blooms: Vec<Bloom>,
+}
+
+impl<T> Default for IndexedReceipts<T> {
+    fn default() -> Self {
+        Self { receipts: Receipts { receipt_vec: Vec::new() }, blooms: Vec::new() }
+    }
+}
+
+impl<T: TxReceipt> IndexedReceipts<T> {
+    /// Creates an empty collection.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends the receipts of the next block and computes its aggregated bloom filter.
+    pub fn push_block(&mut self, receipts: Vec<T>) {
+        self.blooms.push(aggregate_bloom(&receipts));
+        self.receipts.push(receipts);
+    }
+}
+
+impl<T> IndexedReceipts<T> {
+    /// Returns the number of blocks.
+    pub fn len(&self) -> usize {
+        self.blooms.len()
+    }
+
+    /// Returns `true` if the collection contains no blocks.
+    pub fn is_empty(&self) -> bool {
+        self.blooms.is_empty()
+    }
+
+    /// Returns the aggregated bloom filter of the given block, or `None` if it is out of range.
+    pub fn block_bloom(&self, block: usize) -> Option<Bloom> {
+        self.blooms.get(block).copied()
+    }
+
+    /// Returns the indices of the blocks whose bloom filter may contain a log matching the
+    /// filter.
+    pub fn candidate_blocks(&self, filter: &LogFilter) -> Vec<usize> {
+        self.blooms
+            .iter()
+            .enumerate()
+            .filter(|(_, bloom)| filter.matches_bloom(bloom))
+            .map(|(block, _)| block)
+            .collect()
+    }
+
+    /// Returns the receipts of the collection.
+    pub const fn receipts(&self) -> &Receipts<T> {
+        &self.receipts
+    }
+
+    /// Consumes the collection, returning the receipts.
+    pub fn into_receipts(self) -> Receipts<T> {
+        self.receipts
+    }
+}
+
+impl<T: TxReceipt> From<Receipts<T>> for IndexedReceipts<T> {
+    fn from(receipts: Receipts<T>) -> Self {
+        let blooms = receipts.receipt_vec.iter().map(|block| aggregate_bloom(block)).collect();
+        Self { receipts, blooms }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::receipt::test_utils::{log, receipt};
+    use alloy_primitives::Address;
+
+    #[test]
+    fn push_block() {
+        let with_log = |byte| receipt(vec![log(byte)]);
+
+        let mut indexed = IndexedReceipts::new();
+        indexed.push_block(vec![with_log(1)]);
+        indexed.push_block(vec![]);
+        indexed.push_block(vec![with_log(2), with_log(1)]);
+
+        assert_eq!(indexed.len(), 3);
+        assert_eq!(indexed.block_bloom(0), Some(with_log(1).bloom_slow()));
+        assert_eq!(indexed.block_bloom(1), Some(Bloom::ZERO));
+        assert_eq!(indexed.block_bloom(3), None);
+        assert_eq!(
+            indexed.candidate_blocks(&LogFilter::new().address(Address::repeat_byte(2))),
+            vec![2]
+        );
+        assert_eq!(IndexedReceipts::from(indexed.receipts().clone()), indexed);
+        assert_eq!(indexed.into_receipts().len(), 3);
+    }
+}
@@ -0,0 +1,80 @@
+use crate::Receipt;
+use alloc::sync::Arc;
+use alloy_primitives::{map::HashSet, Log};
+
+/// A pool of shared logs, deduplicating identical logs across receipts.
+///
+/// Interning is meant for long-lived receipt caches, e.g. of an RPC server, where many receipts
+/// contain identical logs, such as the same event of the same contract. Each distinct log is
+/// stored once and shared through an [`Arc`], at the cost of hashing every interned log and of
+/// one pointer per log. Logs stay alive as long as the pool or any receipt references them, so a
+/// pool should be dropped or [cleared](LogPool::clear) together with the cache it serves.
+#[derive(Clone, Debug, Default)]
+pub struct LogPool {
+    logs: HashSet<Arc<Log>>,
+}
+
+impl LogPool {
+    /// Creates an empty pool.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of distinct logs in the pool.
+    pub fn len(&self) -> usize {
+        self.logs.len()
+    }
+
+    /// Returns true if the pool contains no logs.
+    pub fn is_empty(&self) -> bool {
+        self.logs.is_empty()
+    }
+
+    /// Removes all logs from the pool. Logs still referenced by receipts are not freed.
+    pub fn clear(&mut self) {
+        self.logs.clear();
+    }
+
+    /// Returns the shared instance of `log`, adding it to the pool if it is not present yet.
+    pub fn intern(&mut self, log: Log) -> Arc<Log> {
+        if let Some(shared) = self.logs.get(&log) {
+            return shared.clone();
+        }
+        let shared = Arc::new(log);
+        self.logs.insert(shared.clone());
+        shared
+    }
+}
+
+impl Receipt<Log> {
+    /// Converts the logs of the receipt into shared logs of the pool, see [`LogPool`].
+    pub fn intern_logs(self, pool: &mut LogPool) -> Receipt<Arc<Log>> {
+        self.map_logs(|log| pool.intern(log))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        receipt::test_utils::{log, receipt},
+        TxReceipt,
+    };
+
+    #[test]
+    fn intern_logs() {
+        let mut pool = LogPool::new();
+        let a = receipt(vec![log(1), log(2)]).intern_logs(&mut pool);
+        let b = receipt(vec![log(2), log(1), log(2)]).intern_logs(&mut pool);
+        assert_eq!(pool.len(), 2);
+        assert!(Arc::ptr_eq(&a.logs[0], &b.logs[1]));
+        assert!(Arc::ptr_eq(&b.logs[0], &b.logs[2]));
+
+        assert_eq!(a.bloom(), receipt(vec![log(1), log(2)]).bloom());
+        assert_eq!(*b.logs[0], log(2));
+
+        pool.clear();
+        assert!(pool.is_empty());
+        assert_eq!(*a.logs[1], log(2));
+    }
+}
@@ -0,0 +1,61 @@
+use crate::receipt::{Eip658Value, Receipt};
+use alloc::vec::Vec;
+use alloy_primitives::{Bloom, Log};
+use core::borrow::Borrow;
+
+/// A [`Receipt`] without the cumulative gas used, for storage that only indexes status and logs.
+///
+/// Converting a [`Receipt`] into a [`LogOnlyReceipt`] is lossy, as the cumulative gas used is
+/// discarded. Converting back requires supplying it again, see [`LogOnlyReceipt::into_receipt`].
+/// Since the cumulative gas used is missing, this type does not implement
+/// [`TxReceipt`](crate::TxReceipt).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct LogOnlyReceipt<T = Log> {
+    /// The status or post state of the transaction.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub status: Eip658Value,
+    /// Log send from contracts.
+    pub logs: Vec<T>,
+}
+
+impl<T> LogOnlyReceipt<T> {
+    /// Converts into a [`Receipt`] with the supplied cumulative gas used.
+    pub fn into_receipt(self, cumulative_gas_used: u128) -> Receipt<T> {
+        Receipt { status: self.status, cumulative_gas_used, logs: self.logs }
+    }
+}
+
+impl<T: Borrow<Log>> LogOnlyReceipt<T> {
+    /// Calculates the bloom filter of the logs.
+    pub fn bloom_slow(&self) -> Bloom {
+        self.logs.iter().map(Borrow::borrow).collect()
+    }
+}
+
+impl<T> From<Receipt<T>> for LogOnlyReceipt<T> {
+    /// Discards the cumulative gas used of the receipt.
+    fn from(receipt: Receipt<T>) -> Self {
+        Self { status: receipt.status, logs: receipt.logs }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::Address;
+
+    #[test]
+    fn receipt_conversion() {
+        let receipt = Receipt {
+            status: false.into(),
+            cumulative_gas_used: 21_000,
+            logs: vec![Log::new_unchecked(Address::repeat_byte(1), vec![], Default::default())],
+        };
+
+        let log_only = LogOnlyReceipt::from(receipt.clone());
+        assert_eq!(log_only.bloom_slow(), receipt.bloom_slow());
+        assert_eq!(log_only.clone().into_receipt(21_000), receipt);
+        assert_eq!(log_only.into_receipt(0).cumulative_gas_used, 0);
+    }
+}
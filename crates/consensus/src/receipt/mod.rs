@@ -1,16 +1,84 @@
-use alloy_primitives::Bloom;
+use alloy_primitives::{Bloom, U256};
 use alloy_rlp::{Buf, BufMut, Header};
 use core::fmt;
 
+mod eip7702;
+pub use eip7702::Eip7702Receipt;
+
 mod envelope;
 pub use envelope::ReceiptEnvelope;
 
+mod filter;
+pub use filter::LogFilter;
+
 mod receipts;
-pub use receipts::{Receipt, ReceiptWithBloom, Receipts};
+#[cfg(feature = "serde")]
+pub use receipts::ReceiptSerializeOptions;
+pub use receipts::{
+    LogOrder, Receipt, ReceiptError, ReceiptWithBloom, Receipts, ReceiptsSummary,
+    MAX_EMPTY_BLOCK_FILL,
+};
+
+mod index;
+pub use index::BlockReceiptsIndex;
+
+mod indexed;
+pub use indexed::IndexedReceipts;
+
+mod intern;
+pub use intern::LogPool;
+
+mod log_only;
+pub use log_only::LogOnlyReceipt;
+
+mod op;
+pub use op::OpReceipt;
+
+mod rows;
+pub use rows::{ReceiptRow, ReceiptRowError};
+
+mod shared;
+pub use shared::ReceiptsArc;
+
+mod rpc;
+#[cfg(feature = "serde")]
+pub use rpc::deserialize_rpc_receipt;
+pub use rpc::RpcLog;
+
+mod sparse;
+pub use sparse::{SparseReceipts, SparseReceiptsError};
 
 mod status;
 pub use status::Eip658Value;
 
+mod toc;
+pub use toc::{encode_with_toc, read_at};
+
+mod utils;
+pub use utils::{
+    bloom_xor, gas_divergence, sort_by_tx_index, strip_tx_indices, validate_block_gas_limit,
+    validate_header_bloom, verify_order, GasDivergence,
+};
+
+mod versioned;
+pub use versioned::{MigrationError, VersionedReceipts};
+
+mod storage;
+pub use storage::{
+    assemble_from_counts, join_from_storage, split_for_storage, AssembleError, ReceiptHeader,
+    SplitReceiptsError,
+};
+
+#[cfg(test)]
+mod test_utils;
+
+#[cfg(feature = "snappy")]
+mod snappy;
+#[cfg(feature = "snappy")]
+pub use snappy::{
+    decode_snappy_receipts, encode_snappy_receipts, SnappyReceiptsError, MAX_SNAPPY_RECEIPTS_LEN,
+};
+
 /// Receipt is the result of a transaction execution.
 #[doc(alias = "TransactionReceipt")]
 #[auto_impl::auto_impl(&, Arc)]
@@ -57,8 +125,25 @@ pub trait TxReceipt: Clone + fmt::Debug + PartialEq + Eq + Send + Sync {
     /// Returns the cumulative gas used in the block after this transaction was executed.
     fn cumulative_gas_used(&self) -> u128;
 
+    /// Returns the cumulative gas used in the block after this transaction was executed, as a
+    /// [`U256`].
+    fn cumulative_gas_used_u256(&self) -> U256 {
+        U256::from(self.cumulative_gas_used())
+    }
+
     /// Returns the logs emitted by this transaction.
     fn logs(&self) -> &[Self::Log];
+
+    /// Returns true if the receipt has no effects to index, i.e. it emitted no logs.
+    ///
+    /// ## Note
+    ///
+    /// This is unrelated to [`TxReceipt::status`]: a failed transaction never emits logs and is
+    /// therefore always empty, but a successful transaction can be empty as well, e.g. a plain
+    /// value transfer. In both cases the transaction still consumed gas.
+    fn is_empty(&self) -> bool {
+        self.logs().is_empty()
+    }
 }
 
 /// Receipt type that knows how to encode and decode itself with a [`Bloom`] value.
@@ -118,13 +203,29 @@ pub trait RlpReceipt: Sized {
 
         Ok(this)
     }
+
+    /// RLP decodes receipt and [`Bloom`] into [`ReceiptWithBloom`] instance, verifying that the
+    /// decoded [`Bloom`] matches the bloom computed from the receipt's logs.
+    ///
+    /// This is slower than [`RlpReceipt::rlp_decode_with_bloom`] because it recomputes the bloom
+    /// filter.
+    fn rlp_decode_verified(buf: &mut &[u8]) -> alloy_rlp::Result<ReceiptWithBloom<Self>>
+    where
+        Self: TxReceipt,
+    {
+        let this = Self::rlp_decode_with_bloom(buf)?;
+        if this.receipt.bloom() != this.logs_bloom {
+            return Err(alloy_rlp::Error::Custom("receipt bloom mismatch"));
+        }
+        Ok(this)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use alloy_eips::eip2718::Encodable2718;
-    use alloy_primitives::{address, b256, bytes, hex, Log, LogData};
+    use alloy_primitives::{address, b256, bytes, hex, Address, Log, LogData, B256};
     use alloy_rlp::{Decodable, Encodable};
 
     // Test vector from: https://eips.ethereum.org/EIPS/eip-2481
@@ -188,6 +289,51 @@ mod tests {
         assert_eq!(receipt, expected);
     }
 
+    #[test]
+    fn decode_verified_rejects_wrong_bloom() {
+        let receipt = Receipt {
+            cumulative_gas_used: 0x1u128,
+            logs: vec![Log {
+                address: address!("0000000000000000000000000000000000000011"),
+                data: LogData::new_unchecked(
+                    vec![b256!("000000000000000000000000000000000000000000000000000000000000dead")],
+                    bytes!("0100ff"),
+                ),
+            }],
+            status: true.into(),
+        };
+
+        let valid = receipt.clone().with_bloom();
+        let data = alloy_rlp::encode(&valid);
+        assert_eq!(Receipt::rlp_decode_verified(&mut &data[..]).unwrap(), valid);
+
+        let invalid = ReceiptWithBloom { receipt, logs_bloom: Bloom::default() };
+        let data = alloy_rlp::encode(&invalid);
+        assert!(Receipt::<Log>::rlp_decode_with_bloom(&mut &data[..]).is_ok());
+        assert_eq!(
+            Receipt::<Log>::rlp_decode_verified(&mut &data[..]),
+            Err(alloy_rlp::Error::Custom("receipt bloom mismatch"))
+        );
+    }
+
+    #[test]
+    fn empty_receipt() {
+        let mut receipt = Receipt::<Log>::default();
+        assert!(TxReceipt::is_empty(&receipt));
+
+        receipt.status = false.into();
+        assert!(TxReceipt::is_empty(&receipt));
+
+        receipt.logs.push(Log::new_unchecked(Address::ZERO, vec![], Default::default()));
+        assert!(!TxReceipt::is_empty(&receipt.with_bloom()));
+    }
+
+    #[test]
+    fn cumulative_gas_used_u256() {
+        let receipt = Receipt::<Log> { cumulative_gas_used: u128::MAX, ..Default::default() };
+        assert_eq!(receipt.cumulative_gas_used_u256(), U256::from(u128::MAX));
+    }
+
     #[test]
     fn gigantic_receipt() {
         let receipt = Receipt {
@@ -227,4 +373,122 @@ mod tests {
         // let (decoded, _) = Receipt::from_compact(&data[..], data.len());
         assert_eq!(decoded, receipt);
     }
+
+    /// Reference RLP encoding of a receipt with bloom, written independently of `alloy_rlp`.
+    mod reference {
+        use super::*;
+
+        fn length_prefix(offset: u8, len: usize) -> Vec<u8> {
+            if len < 56 {
+                return vec![offset + len as u8];
+            }
+            let be = len.to_be_bytes();
+            let be = &be[be.iter().position(|&b| b != 0).unwrap()..];
+            let mut out = vec![offset + 55 + be.len() as u8];
+            out.extend_from_slice(be);
+            out
+        }
+
+        fn string(bytes: &[u8]) -> Vec<u8> {
+            if bytes.len() == 1 && bytes[0] < 0x80 {
+                return bytes.to_vec();
+            }
+            let mut out = length_prefix(0x80, bytes.len());
+            out.extend_from_slice(bytes);
+            out
+        }
+
+        fn list(items: impl IntoIterator<Item = Vec<u8>>) -> Vec<u8> {
+            let payload = items.into_iter().flatten().collect::<Vec<_>>();
+            let mut out = length_prefix(0xc0, payload.len());
+            out.extend(payload);
+            out
+        }
+
+        fn uint(value: u128) -> Vec<u8> {
+            let be = value.to_be_bytes();
+            string(&be[be.iter().position(|&b| b != 0).unwrap_or(be.len())..])
+        }
+
+        pub(super) fn encode(receipt: &ReceiptWithBloom) -> Vec<u8> {
+            let status = match receipt.receipt.status {
+                Eip658Value::Eip658(success) => uint(success as u128),
+                Eip658Value::PostState(root) => string(root.as_slice()),
+            };
+            let logs = receipt.receipt.logs.iter().map(|log| {
+                list([
+                    string(log.address.as_slice()),
+                    list(log.topics().iter().map(|topic| string(topic.as_slice()))),
+                    string(&log.data.data),
+                ])
+            });
+            list([
+                status,
+                uint(receipt.receipt.cumulative_gas_used),
+                string(receipt.logs_bloom.as_slice()),
+                list(logs),
+            ])
+        }
+    }
+
+    #[test]
+    fn encoding_matches_reference() {
+        use arbitrary::Arbitrary;
+        use rand::{Rng, SeedableRng};
+
+        // A fixed seed keeps failures reproducible.
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0x7ece1e7);
+        for _ in 0..500 {
+            let mut bytes = [0u8; 2048];
+            rng.fill(bytes.as_mut_slice());
+            let receipt =
+                ReceiptWithBloom::<Receipt>::arbitrary(&mut arbitrary::Unstructured::new(&bytes))
+                    .unwrap();
+
+            let encoded = alloy_rlp::encode(&receipt);
+            assert_eq!(encoded, reference::encode(&receipt), "{receipt:?}");
+            assert_eq!(receipt.length(), encoded.len());
+            assert_eq!(ReceiptWithBloom::decode(&mut &encoded[..]).unwrap(), receipt);
+        }
+    }
+
+    #[test]
+    fn encoding_edge_cases() {
+        // Successful receipt without gas, logs or bloom bits.
+        let receipt = Receipt::<Log>::default().with_bloom();
+        let mut expected = hex!("f901060180b90100").to_vec();
+        expected.extend([0; 256]);
+        expected.push(0xc0);
+        assert_eq!(alloy_rlp::encode(&receipt), expected);
+        assert_eq!(reference::encode(&receipt), expected);
+
+        let fixtures = [
+            Receipt { status: false.into(), cumulative_gas_used: 0, logs: vec![] },
+            Receipt {
+                status: Eip658Value::PostState(B256::ZERO),
+                cumulative_gas_used: u128::MAX,
+                logs: vec![],
+            },
+            Receipt {
+                status: true.into(),
+                cumulative_gas_used: 0x7f,
+                logs: vec![Log::new_unchecked(Address::ZERO, vec![], bytes!("00"))],
+            },
+            Receipt {
+                status: true.into(),
+                cumulative_gas_used: 0x80,
+                logs: vec![Log::new_unchecked(
+                    Address::ZERO,
+                    vec![B256::ZERO; 4],
+                    vec![1; 55].into(),
+                )],
+            },
+        ];
+        for receipt in fixtures {
+            let receipt = receipt.with_bloom();
+            let encoded = alloy_rlp::encode(&receipt);
+            assert_eq!(encoded, reference::encode(&receipt), "{receipt:?}");
+            assert_eq!(ReceiptWithBloom::decode(&mut &encoded[..]).unwrap(), receipt);
+        }
+    }
 }
@@ -0,0 +1,272 @@
+use crate::receipt::{Eip658Value, Receipt, ReceiptWithBloom, RlpReceipt, TxReceipt};
+use alloc::string::String;
+use alloy_primitives::{Bloom, Log};
+use alloy_rlp::{Buf, BufMut, Decodable, Encodable, EMPTY_LIST_CODE};
+
+/// A receipt extended with the L1 fee accounting fields reported by OP-stack chains.
+///
+/// All L1 fields are optional. In RLP they are appended after the fields of the inner receipt,
+/// in declaration order, up to the last present field. Absent fields before it are encoded as an
+/// empty list, which no L1 field encodes to, so that the following fields keep their position.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[doc(alias = "OptimismReceipt")]
+pub struct OpReceipt<R = Receipt<Log>> {
+    /// The base receipt.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub inner: R,
+    /// The fee paid for posting the transaction data to L1.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "alloy_serde::quantity::opt"
+        )
+    )]
+    pub l1_fee: Option<u128>,
+    /// The amount of L1 gas used for the transaction data.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "alloy_serde::quantity::opt"
+        )
+    )]
+    pub l1_gas_used: Option<u128>,
+    /// The L1 gas price at the time the transaction data was posted.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "alloy_serde::quantity::opt"
+        )
+    )]
+    pub l1_gas_price: Option<u128>,
+    /// The L1 fee scalar, as the decimal string reported by the node, e.g. `"0.684"`.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub l1_fee_scalar: Option<String>,
+}
+
+impl<R> OpReceipt<R> {
+    /// Creates a new [`OpReceipt`] without any L1 fee fields.
+    pub const fn new(inner: R) -> Self {
+        Self { inner, l1_fee: None, l1_gas_used: None, l1_gas_price: None, l1_fee_scalar: None }
+    }
+
+    /// Consumes the receipt, returning the base receipt.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Returns the L1 fields in RLP order, up to the last present field.
+    fn rlp_l1_fields(&self) -> impl Iterator<Item = Option<&dyn Encodable>> {
+        let fields: [Option<&dyn Encodable>; 4] = [
+            self.l1_fee.as_ref().map(|v| v as _),
+            self.l1_gas_used.as_ref().map(|v| v as _),
+            self.l1_gas_price.as_ref().map(|v| v as _),
+            self.l1_fee_scalar.as_ref().map(|v| v as _),
+        ];
+        let len = fields.iter().rposition(Option::is_some).map_or(0, |i| i + 1);
+        fields.into_iter().take(len)
+    }
+}
+
+/// Decodes an L1 field, or `None` if it is an empty list placeholder.
+fn rlp_decode_l1_field<T: Decodable>(buf: &mut &[u8]) -> alloy_rlp::Result<Option<T>> {
+    if buf.first() == Some(&EMPTY_LIST_CODE) {
+        buf.advance(1);
+        return Ok(None);
+    }
+    Decodable::decode(buf).map(Some)
+}
+
+impl<R> From<R> for OpReceipt<R> {
+    fn from(inner: R) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl<R: TxReceipt> TxReceipt for OpReceipt<R> {
+    type Log = R::Log;
+
+    fn status_or_post_state(&self) -> Eip658Value {
+        self.inner.status_or_post_state()
+    }
+
+    fn status(&self) -> bool {
+        self.inner.status()
+    }
+
+    fn bloom(&self) -> Bloom {
+        self.inner.bloom()
+    }
+
+    fn bloom_cheap(&self) -> Option<Bloom> {
+        self.inner.bloom_cheap()
+    }
+
+    fn cumulative_gas_used(&self) -> u128 {
+        self.inner.cumulative_gas_used()
+    }
+
+    fn logs(&self) -> &[Self::Log] {
+        self.inner.logs()
+    }
+}
+
+impl<R: RlpReceipt> RlpReceipt for OpReceipt<R> {
+    fn rlp_encoded_fields_length_with_bloom(&self, bloom: Bloom) -> usize {
+        self.inner.rlp_encoded_fields_length_with_bloom(bloom)
+            + self.rlp_l1_fields().map(|field| field.map_or(1, |v| v.length())).sum::<usize>()
+    }
+
+    fn rlp_encode_fields_with_bloom(&self, bloom: Bloom, out: &mut dyn BufMut) {
+        self.inner.rlp_encode_fields_with_bloom(bloom, out);
+        for field in self.rlp_l1_fields() {
+            match field {
+                Some(v) => v.encode(out),
+                None => out.put_u8(EMPTY_LIST_CODE),
+            }
+        }
+    }
+
+    fn rlp_decode_fields_with_bloom(buf: &mut &[u8]) -> alloy_rlp::Result<ReceiptWithBloom<Self>> {
+        let ReceiptWithBloom { receipt, logs_bloom } = R::rlp_decode_fields_with_bloom(buf)?;
+        let mut this = Self::new(receipt);
+
+        let mut placeholder = false;
+        if !buf.is_empty() {
+            this.l1_fee = rlp_decode_l1_field(buf)?;
+            placeholder = this.l1_fee.is_none();
+        }
+        if !buf.is_empty() {
+            this.l1_gas_used = rlp_decode_l1_field(buf)?;
+            placeholder = this.l1_gas_used.is_none();
+        }
+        if !buf.is_empty() {
+            this.l1_gas_price = rlp_decode_l1_field(buf)?;
+            placeholder = this.l1_gas_price.is_none();
+        }
+        if !buf.is_empty() {
+            this.l1_fee_scalar = rlp_decode_l1_field(buf)?;
+            placeholder = this.l1_fee_scalar.is_none();
+        }
+        // Absent trailing fields are omitted, so a trailing placeholder is not canonical.
+        if placeholder {
+            return Err(alloy_rlp::Error::Custom("trailing empty L1 field"));
+        }
+
+        Ok(ReceiptWithBloom { receipt: this, logs_bloom })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256, bytes, LogData};
+
+    fn receipt() -> OpReceipt {
+        OpReceipt {
+            inner: Receipt {
+                status: true.into(),
+                cumulative_gas_used: 0xf1740,
+                logs: vec![Log {
+                    address: address!("4200000000000000000000000000000000000006"),
+                    data: LogData::new_unchecked(
+                        vec![b256!(
+                            "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+                        )],
+                        bytes!("01"),
+                    ),
+                }],
+            },
+            l1_fee: Some(0x105d4b2024),
+            l1_gas_used: Some(0x800),
+            l1_gas_price: Some(0x5d749a07e),
+            l1_fee_scalar: Some("0.684".into()),
+        }
+    }
+
+    #[test]
+    fn rlp_roundtrip() {
+        let receipt = ReceiptWithBloom::from(receipt());
+        let data = alloy_rlp::encode(&receipt);
+        assert_eq!(alloy_rlp::Decodable::decode(&mut &data[..]), Ok(receipt.clone()));
+
+        // Without L1 fields the encoding is identical to the base receipt.
+        let base = ReceiptWithBloom::new(
+            OpReceipt::new(receipt.receipt.inner.clone()),
+            receipt.logs_bloom,
+        );
+        let base_data = alloy_rlp::encode(&base);
+        assert_eq!(base_data, alloy_rlp::encode(receipt.receipt.inner.with_bloom()));
+        assert_eq!(alloy_rlp::Decodable::decode(&mut &base_data[..]), Ok(base));
+    }
+
+    #[test]
+    fn rlp_roundtrip_absent_fields() {
+        let full = receipt();
+        for present in 0..16 {
+            let receipt = ReceiptWithBloom::from(OpReceipt {
+                l1_fee: full.l1_fee.filter(|_| present & 1 != 0),
+                l1_gas_used: full.l1_gas_used.filter(|_| present & 2 != 0),
+                l1_gas_price: full.l1_gas_price.filter(|_| present & 4 != 0),
+                l1_fee_scalar: full.l1_fee_scalar.clone().filter(|_| present & 8 != 0),
+                ..full.clone()
+            });
+            let data = alloy_rlp::encode(&receipt);
+            assert_eq!(data.len(), receipt.length());
+            assert_eq!(alloy_rlp::Decodable::decode(&mut &data[..]), Ok(receipt), "{present:04b}");
+        }
+
+        // A placeholder for an absent trailing field is rejected.
+        let receipt =
+            OpReceipt { l1_gas_used: None, l1_gas_price: None, l1_fee_scalar: None, ..full };
+        let mut fields = Vec::new();
+        receipt.rlp_encode_fields_with_bloom(Bloom::ZERO, &mut fields);
+        fields.push(EMPTY_LIST_CODE);
+        let mut data = Vec::new();
+        alloy_rlp::Header { list: true, payload_length: fields.len() }.encode(&mut data);
+        data.extend(fields);
+        assert_eq!(
+            ReceiptWithBloom::<OpReceipt>::decode(&mut &data[..]),
+            Err(alloy_rlp::Error::Custom("trailing empty L1 field"))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_op_rpc() {
+        // Trimmed from an OP mainnet (pre-Ecotone) `eth_getTransactionReceipt` response.
+        let json = r#"{
+            "status": "0x1",
+            "cumulativeGasUsed": "0xf1740",
+            "logs": [],
+            "l1Fee": "0x105d4b2024",
+            "l1GasUsed": "0x800",
+            "l1GasPrice": "0x5d749a07e",
+            "l1FeeScalar": "0.684"
+        }"#;
+        let receipt: OpReceipt<Receipt<()>> = serde_json::from_str(json).unwrap();
+        assert_eq!(receipt.l1_fee, Some(0x105d4b2024));
+        assert_eq!(receipt.l1_gas_used, Some(0x800));
+        assert_eq!(receipt.l1_gas_price, Some(0x5d749a07e));
+        assert_eq!(receipt.l1_fee_scalar.as_deref(), Some("0.684"));
+        assert_eq!(
+            serde_json::to_value(&receipt).unwrap(),
+            serde_json::from_str::<serde_json::Value>(json).unwrap()
+        );
+
+        let json = r#"{"status":"0x1","cumulativeGasUsed":"0x1","logs":[]}"#;
+        let receipt: OpReceipt<Receipt<()>> = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            receipt,
+            OpReceipt::new(Receipt { cumulative_gas_used: 1, ..Default::default() })
+        );
+        assert_eq!(serde_json::to_string(&receipt).unwrap(), json);
+    }
+}
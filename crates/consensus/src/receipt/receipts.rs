@@ -1,11 +1,27 @@
-use crate::receipt::{Eip658Value, RlpReceipt, TxReceipt};
-use alloc::{vec, vec::Vec};
-use alloy_primitives::{Bloom, Log};
+use crate::receipt::{Eip658Value, LogFilter, RlpReceipt, RpcLog, TxReceipt};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    format,
+    string::String,
+    vec,
+    vec::Vec,
+};
+use alloy_primitives::{keccak256, map::HashMap, Address, Bloom, BloomInput, Bytes, Log, B256};
 use alloy_rlp::{BufMut, Decodable, Encodable};
-use core::{borrow::Borrow, fmt};
+use core::{
+    borrow::Borrow,
+    fmt,
+    hash::{BuildHasher, Hash},
+    ops::Range,
+};
 use derive_more::{DerefMut, From, IntoIterator};
 
 /// Receipt containing result of transaction execution.
+///
+/// The [`Default`] receipt is a successful [EIP-658] receipt with no gas used and no logs, see
+/// also [`Receipt::default_success`].
+///
+/// [EIP-658]: https://eips.ethereum.org/EIPS/eip-658
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
@@ -21,15 +37,211 @@ pub struct Receipt<T = Log> {
     #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
     pub cumulative_gas_used: u128,
     /// Log send from contracts.
+    ///
+    /// Defaults to no logs if the field is missing when deserializing.
+    #[cfg_attr(feature = "serde", serde(default = "Vec::new"))]
     pub logs: Vec<T>,
 }
 
+/// The order of the logs passed to [`Receipt::from_execution`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum LogOrder {
+    /// The logs are already in emission order, which is the case for logs collected from an EVM.
+    #[default]
+    AsGiven,
+    /// The logs are in arbitrary order and carry their emission index, one per log.
+    ByEmissionIndex(Vec<u64>),
+}
+
+impl<T> Receipt<T> {
+    /// Returns an explicitly successful [EIP-658] receipt with no gas used and no logs.
+    ///
+    /// This is the same as [`Receipt::default`], but does not rely on the default of
+    /// [`Eip658Value`].
+    ///
+    /// [EIP-658]: https://eips.ethereum.org/EIPS/eip-658
+    pub const fn default_success() -> Self {
+        Self { status: Eip658Value::Eip658(true), cumulative_gas_used: 0, logs: Vec::new() }
+    }
+
+    /// Retains only the logs matching the predicate, in place.
+    ///
+    /// Note: this does not touch any bloom computed from the previous set of logs.
+    pub fn retain_logs(&mut self, f: impl FnMut(&T) -> bool) {
+        self.logs.retain(f);
+    }
+
+    /// Consumes the receipt and returns a new one containing only the logs matching the
+    /// predicate. Status and cumulative gas are left unchanged.
+    ///
+    /// Use [`Receipt::with_bloom`] on the result to compute the bloom of the reduced set of
+    /// logs.
+    pub fn filter_logs(mut self, f: impl Fn(&T) -> bool) -> Self {
+        self.retain_logs(f);
+        self
+    }
+
+    /// Returns `true` if the receipt's bloom filter has any bit set.
+    ///
+    /// Every log sets bits for at least its address, so this is equivalent to
+    /// `!self.logs.is_empty()` and does not require computing the bloom.
+    pub fn has_any_log_bloom_bit(&self) -> bool {
+        !self.logs.is_empty()
+    }
+
+    /// Returns a parallel iterator over the logs of the receipt.
+    ///
+    /// This is meant for receipts with many logs that are expensive to process, e.g. ABI
+    /// decoding. For a few logs, iterating over [`TxReceipt::logs`] serially is faster.
+    #[cfg(feature = "rayon")]
+    pub fn logs_par(&self) -> impl rayon::iter::ParallelIterator<Item = &T>
+    where
+        T: Sync,
+    {
+        use rayon::iter::IntoParallelRefIterator;
+
+        self.logs.par_iter()
+    }
+
+    /// Returns `true` if both receipts have the same status and cumulative gas used, and contain
+    /// the same logs regardless of their order.
+    ///
+    /// Canonical receipts always have their logs in emission order, so this is only meant as a
+    /// reconciliation tool for data sourced from providers that do not preserve log order. Use
+    /// [`PartialEq`] for strict comparison.
+    pub fn eq_logs_unordered(&self, other: &Self) -> bool
+    where
+        T: Hash + Eq,
+    {
+        if self.status != other.status
+            || self.cumulative_gas_used != other.cumulative_gas_used
+            || self.logs.len() != other.logs.len()
+        {
+            return false;
+        }
+
+        let mut counts = HashMap::<&T, usize>::default();
+        for log in &self.logs {
+            *counts.entry(log).or_default() += 1;
+        }
+        other.logs.iter().all(|log| match counts.get_mut(log) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            }
+            _ => false,
+        })
+    }
+
+    /// Converts the logs of the receipt with the given function, leaving status and cumulative
+    /// gas unchanged.
+    pub fn map_logs<U>(self, f: impl FnMut(T) -> U) -> Receipt<U> {
+        Receipt {
+            status: self.status,
+            cumulative_gas_used: self.cumulative_gas_used,
+            logs: self.logs.into_iter().map(f).collect(),
+        }
+    }
+
+    /// Wraps each log in an [`RpcLog`] with `removed` set to `false`.
+    ///
+    /// Use [`Receipt::map_logs`] to supply a per-log `removed` flag instead.
+    pub fn into_rpc_logs(self) -> Receipt<RpcLog<T>> {
+        self.map_logs(RpcLog::from)
+    }
+
+    /// Returns a concise one-line description of the receipt for log output, e.g.
+    /// `success gas=21000 logs=3`.
+    ///
+    /// The gas is the cumulative gas used, as the gas used by the individual transaction is not
+    /// known without the preceding receipt. Pre-[EIP-658] receipts are reported as successful, see
+    /// [`Eip658Value::coerce_status`].
+    ///
+    /// [EIP-658]: https://eips.ethereum.org/EIPS/eip-658
+    pub fn short_summary(&self) -> String {
+        let status = if self.status.coerce_status() { "success" } else { "fail" };
+        format!("{status} gas={} logs={}", self.cumulative_gas_used, self.logs.len())
+    }
+
+    /// Creates a receipt from the result of executing a transaction.
+    ///
+    /// Logs must be in the order they were emitted. With [`LogOrder::ByEmissionIndex`], the logs
+    /// are sorted by the given indices first.
+    ///
+    /// Returns an error if the number of emission indices does not match the number of logs, or
+    /// if an emission index occurs more than once.
+    pub fn from_execution(
+        success: bool,
+        cumulative_gas_used: u128,
+        mut logs: Vec<T>,
+        log_order: LogOrder,
+    ) -> Result<Self, ReceiptError> {
+        if let LogOrder::ByEmissionIndex(indices) = log_order {
+            if indices.len() != logs.len() {
+                return Err(ReceiptError::LogOrderLengthMismatch {
+                    logs: logs.len(),
+                    indices: indices.len(),
+                });
+            }
+            let mut indexed: Vec<_> = indices.into_iter().zip(logs).collect();
+            indexed.sort_unstable_by_key(|(index, _)| *index);
+            if let Some(w) = indexed.windows(2).find(|w| w[0].0 == w[1].0) {
+                return Err(ReceiptError::DuplicateEmissionIndex(w[0].0));
+            }
+            logs = indexed.into_iter().map(|(_, log)| log).collect();
+        }
+        Ok(Self { status: success.into(), cumulative_gas_used, logs })
+    }
+
+    /// Returns true if both receipts describe the same execution outcome, i.e. they have the same
+    /// [coerced status](Eip658Value::coerce_status), cumulative gas used and logs.
+    ///
+    /// Unlike [`PartialEq`], this treats a pre-[EIP-658] post-state root as opaque, so that a
+    /// re-executed receipt with a status byte compares equal to a stored receipt with a post-state
+    /// root. Since a post-state root always coerces to success, a failed re-execution never
+    /// matches a pre-[EIP-658] receipt.
+    ///
+    /// [EIP-658]: https://eips.ethereum.org/EIPS/eip-658
+    pub fn outcome_eq(&self, other: &Self) -> bool
+    where
+        T: PartialEq,
+    {
+        self.status.coerce_status() == other.status.coerce_status()
+            && self.cumulative_gas_used == other.cumulative_gas_used
+            && self.logs == other.logs
+    }
+
+    /// Splits the receipt into two receipts with the same status and cumulative gas used, the
+    /// first containing the logs before index `at` and the second the remaining logs.
+    ///
+    /// `at` is clamped to the number of logs. This is mostly useful to construct test cases with
+    /// unusually distributed logs, as the resulting receipts are not consistent with a block.
+    pub fn split_logs(mut self, at: usize) -> (Self, Self) {
+        let tail = self.logs.split_off(at.min(self.logs.len()));
+        let second =
+            Self { status: self.status, cumulative_gas_used: self.cumulative_gas_used, logs: tail };
+        (self, second)
+    }
+
+    /// Clones the receipt, duplicating each log with the given function instead of [`Clone`],
+    /// e.g. to intern logs or to copy them into an arena.
+    pub fn clone_logs_with(&self, f: impl FnMut(&T) -> T) -> Self {
+        Self {
+            status: self.status,
+            cumulative_gas_used: self.cumulative_gas_used,
+            logs: self.logs.iter().map(f).collect(),
+        }
+    }
+}
+
 impl<T> Receipt<T>
 where
     T: Borrow<Log>,
 {
     /// Calculates [`Log`]'s bloom filter. this is slow operation and [ReceiptWithBloom] can
-    /// be used to cache this value.
+    /// be used to cache this value. Enable the `asm-keccak` feature to hash with the assembly
+    /// keccak implementation, which computed the bloom of 1000 logs about 1.5x faster in the
+    /// `bloom` benchmark on x86_64 (1.2-1.5ms instead of 1.7-2.1ms).
     pub fn bloom_slow(&self) -> Bloom {
         self.logs.iter().map(Borrow::borrow).collect()
     }
@@ -39,6 +251,228 @@ where
     pub fn with_bloom(self) -> ReceiptWithBloom<Self> {
         ReceiptWithBloom { logs_bloom: self.bloom_slow(), receipt: self }
     }
+
+    /// Returns the set of distinct topics across all logs of the receipt.
+    pub fn distinct_topics(&self) -> BTreeSet<B256> {
+        self.logs.iter().flat_map(|log| log.borrow().topics()).copied().collect()
+    }
+
+    /// Returns the set of distinct first topics, i.e. event signatures, across all logs of the
+    /// receipt. Logs without topics are skipped.
+    pub fn topics0_only(&self) -> BTreeSet<B256> {
+        self.logs.iter().filter_map(|log| log.borrow().topics().first()).copied().collect()
+    }
+
+    /// Returns `true` if every log of the receipt was emitted by one of the `allowed` addresses.
+    pub fn all_logs_from(&self, allowed: &[Address]) -> bool {
+        self.logs.iter().all(|log| allowed.contains(&log.borrow().address))
+    }
+
+    /// Returns the logs of the receipt that were not emitted by any of the `allowed` addresses.
+    pub fn foreign_logs(&self, allowed: &[Address]) -> Vec<&Log> {
+        self.logs.iter().map(Borrow::borrow).filter(|log| !allowed.contains(&log.address)).collect()
+    }
+
+    /// Returns each log of the receipt broken into its address, topics and data.
+    ///
+    /// This is the shape consumed by most ABI decoders.
+    pub fn decomposed_logs(&self) -> Vec<(Address, Vec<B256>, Bytes)> {
+        self.logs
+            .iter()
+            .map(Borrow::borrow)
+            .map(|log| (log.address, log.topics().to_vec(), log.data.data.clone()))
+            .collect()
+    }
+
+    /// Calculates the bloom filter over only the logs matching the predicate, e.g. the logs of a
+    /// particular address for a secondary index.
+    ///
+    /// Unless all logs match, this is not the canonical bloom of the receipt, see
+    /// [`Receipt::bloom_slow`] for that.
+    pub fn partial_bloom(&self, f: impl Fn(&T) -> bool) -> Bloom {
+        self.logs.iter().filter(|log| f(log)).map(Borrow::borrow).collect()
+    }
+
+    /// Returns the logs of the receipt grouped by their emitting address.
+    ///
+    /// Within each group, logs keep their order of emission.
+    pub fn logs_grouped_by_address(&self) -> BTreeMap<Address, Vec<&Log>> {
+        let mut groups = BTreeMap::<_, Vec<_>>::new();
+        for log in self.logs.iter().map(Borrow::borrow) {
+            groups.entry(log.address).or_default().push(log);
+        }
+        groups
+    }
+
+    /// Returns the position of the first log of the receipt that equals `log`.
+    ///
+    /// Together with the position of the receipt in its block, this gives the coordinates needed
+    /// to construct a proof for the log.
+    pub fn log_index_in_receipt(&self, log: &Log) -> Option<usize> {
+        self.logs.iter().position(|l| l.borrow() == log)
+    }
+
+    /// Calculates the same bloom filter as [`Receipt::bloom_slow`], hashing each distinct
+    /// (address, topics) pair only once.
+    ///
+    /// This is faster for receipts emitting many logs of the same shape, e.g. batched transfers,
+    /// at the cost of hashing the pairs into a set. See the `bloom` benchmark: on receipts with
+    /// few distinct shapes it is an order of magnitude faster, while on receipts whose logs are
+    /// all distinct it is slower than [`Receipt::bloom_slow`].
+    #[cfg(feature = "bloom-cache")]
+    pub fn bloom_cached(&self) -> Bloom {
+        let mut seen = alloy_primitives::map::HashSet::<(Address, &[B256])>::default();
+        let mut bloom = Bloom::ZERO;
+        for log in self.logs.iter().map(Borrow::borrow) {
+            if seen.insert((log.address, log.topics())) {
+                bloom.accrue_log(log);
+            }
+        }
+        bloom
+    }
+
+    /// Creates a receipt for the result of a simulated call, along with its bloom filter.
+    ///
+    /// Since a simulated call does not execute as part of a block, the gas used by the call is
+    /// used as the cumulative gas used.
+    pub fn from_call_result(success: bool, gas_used: u128, logs: Vec<T>) -> (Self, Bloom) {
+        let receipt = Self { status: success.into(), cumulative_gas_used: gas_used, logs };
+        let bloom = receipt.bloom_slow();
+        (receipt, bloom)
+    }
+
+    /// Returns the indices of the logs whose event signature, i.e. first topic, is contained in
+    /// `expected`, but whose total number of topics differs from the expected arity.
+    ///
+    /// Logs with an unknown or missing event signature are skipped.
+    pub fn validate_topic_arities<S: BuildHasher>(
+        &self,
+        expected: &HashMap<B256, usize, S>,
+    ) -> Vec<usize> {
+        self.logs
+            .iter()
+            .map(Borrow::borrow)
+            .enumerate()
+            .filter(|(_, log)| {
+                let topics = log.topics();
+                topics
+                    .first()
+                    .and_then(|topic0| expected.get(topic0))
+                    .is_some_and(|&arity| arity != topics.len())
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Returns a hash of the receipt that does not depend on the order of its logs.
+    ///
+    /// This is the [`Receipt::cache_key`] of the receipt with its logs sorted by their RLP
+    /// encoding. It is meant for reconciling receipts of providers that disagree on log order
+    /// only, and is **not** the consensus hash of the receipt. Use [`Receipt::cache_key`] or the
+    /// RLP encoding of the receipt where log order matters.
+    pub fn canonical_hash_unordered(&self) -> B256 {
+        let mut logs: Vec<&Log> = self.logs.iter().map(Borrow::borrow).collect();
+        logs.sort_by_cached_key(|log| alloy_rlp::encode(log));
+        Receipt { status: self.status, cumulative_gas_used: self.cumulative_gas_used, logs }
+            .cache_key()
+    }
+
+    /// Returns the first log whose event signature, i.e. first topic, equals `signature`.
+    pub fn find_event(&self, signature: B256) -> Option<&Log> {
+        self.logs.iter().map(Borrow::borrow).find(|log| log.topics().first() == Some(&signature))
+    }
+
+    /// Returns the distinct topics that set at least one bit of the receipt's bloom that no other
+    /// address or topic of the receipt sets, sorted.
+    ///
+    /// Queries for any topic of the receipt match its bloom, but for the topics not returned, all
+    /// bits are already set by other inputs. Such topics no longer distinguish the receipt in a
+    /// bloom query, which becomes more likely as the number of distinct inputs grows.
+    pub fn bloom_effective_topics(&self) -> Vec<B256> {
+        let topics = self.distinct_topics();
+        let addresses: BTreeSet<Address> =
+            self.logs.iter().map(|log| log.borrow().address).collect();
+        let input_bloom = |input: &[u8]| {
+            let mut bloom = Bloom::ZERO;
+            bloom.accrue(BloomInput::Raw(input));
+            bloom
+        };
+
+        // Bits set by at least one input, and by more than one input.
+        let (mut once, mut multiple) = (Bloom::ZERO, Bloom::ZERO);
+        let inputs = addresses.iter().map(|address| address.as_slice());
+        for bloom in inputs.chain(topics.iter().map(|topic| topic.as_slice())).map(input_bloom) {
+            multiple |= once & bloom;
+            once |= bloom;
+        }
+
+        topics
+            .into_iter()
+            .filter(|topic| input_bloom(topic.as_slice()) & !multiple != Bloom::ZERO)
+            .collect()
+    }
+
+    /// Returns the indices of the logs without topics and without data.
+    ///
+    /// Such logs are valid, e.g. an anonymous event without parameters emitted with `LOG0`, but
+    /// they are rare and often indicate a decoding error, which makes this a useful diagnostic.
+    pub fn find_empty_logs(&self) -> Vec<usize> {
+        self.logs
+            .iter()
+            .map(Borrow::borrow)
+            .enumerate()
+            .filter(|(_, log)| log.topics().is_empty() && log.data.data.is_empty())
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Returns the number of logs per event signature, i.e. first topic, emitted by the receipt.
+    ///
+    /// Logs without topics are skipped.
+    pub fn event_histogram(&self) -> BTreeMap<B256, usize> {
+        let mut histogram = BTreeMap::new();
+        for topic0 in self.logs.iter().filter_map(|log| log.borrow().topics().first()) {
+            *histogram.entry(*topic0).or_default() += 1;
+        }
+        histogram
+    }
+}
+
+#[cfg(feature = "json-core")]
+impl<T> Receipt<T>
+where
+    T: Borrow<Log>,
+{
+    /// Writes the JSON representation of the receipt to the given writer, without going through
+    /// `serde_json`.
+    ///
+    /// The output matches the `serde` representation of [`Receipt`], e.g.
+    /// `{"status":"0x1","cumulativeGasUsed":"0x5208","logs":[]}`.
+    pub fn write_json_core<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        match self.status {
+            Eip658Value::Eip658(status) => write!(w, r#"{{"status":"{:#x}""#, status as u8)?,
+            Eip658Value::PostState(root) => write!(w, r#"{{"root":"{root:#x}""#)?,
+        }
+        write!(w, r#","cumulativeGasUsed":"{:#x}","logs":["#, self.cumulative_gas_used)?;
+        for (i, log) in self.logs.iter().map(Borrow::borrow).enumerate() {
+            if i > 0 {
+                w.write_char(',')?;
+            }
+            write!(w, r#"{{"address":"{:#x}","topics":["#, log.address)?;
+            for (j, topic) in log.topics().iter().enumerate() {
+                if j > 0 {
+                    w.write_char(',')?;
+                }
+                write!(w, r#""{topic:#x}""#)?;
+            }
+            w.write_str(r#"],"data":"0x"#)?;
+            for byte in log.data.data.iter() {
+                write!(w, "{byte:02x}")?;
+            }
+            w.write_str(r#""}"#)?;
+        }
+        w.write_str("]}")
+    }
 }
 
 impl<T> TxReceipt for Receipt<T>
@@ -93,13 +527,83 @@ impl<T: Encodable + Decodable> RlpReceipt for Receipt<T> {
     }
 }
 
+impl<T: Encodable> Receipt<T> {
+    /// Returns the length of the RLP encoded receipt without the bloom filter, including the RLP
+    /// header.
+    pub fn rlp_encoded_length_without_bloom(&self) -> usize {
+        self.rlp_header_without_bloom().length_with_payload()
+    }
+
+    /// RLP encodes the status, cumulative gas used and logs of the receipt as a list, omitting
+    /// the bloom filter.
+    pub fn rlp_encode_without_bloom(&self, out: &mut dyn BufMut) {
+        self.rlp_header_without_bloom().encode(out);
+        self.status.encode(out);
+        self.cumulative_gas_used.encode(out);
+        self.logs.encode(out);
+    }
+
+    /// Returns a content-addressed key of the receipt, i.e. the keccak256 hash of
+    /// [`Receipt::rlp_encode_without_bloom`].
+    ///
+    /// Receipts with equal status, cumulative gas used and logs have equal keys, which makes it
+    /// suitable for deduplicating receipts across re-executions.
+    pub fn cache_key(&self) -> B256 {
+        let mut buf = Vec::with_capacity(self.rlp_encoded_length_without_bloom());
+        self.rlp_encode_without_bloom(&mut buf);
+        keccak256(buf)
+    }
+
+    fn rlp_header_without_bloom(&self) -> alloy_rlp::Header {
+        alloy_rlp::Header {
+            list: true,
+            payload_length: self.status.length()
+                + self.cumulative_gas_used.length()
+                + self.logs.length(),
+        }
+    }
+}
+
+impl<T: Decodable> Receipt<T> {
+    /// RLP decodes a receipt encoded by [`Receipt::rlp_encode_without_bloom`].
+    pub fn rlp_decode_without_bloom(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let header = alloy_rlp::Header::decode(buf)?;
+        if !header.list {
+            return Err(alloy_rlp::Error::UnexpectedString);
+        }
+
+        if header.payload_length > buf.len() {
+            return Err(alloy_rlp::Error::InputTooShort);
+        }
+
+        let mut fields_buf = &buf[..header.payload_length];
+        let status = Decodable::decode(&mut fields_buf)?;
+        let cumulative_gas_used = Decodable::decode(&mut fields_buf)?;
+        let logs = Decodable::decode(&mut fields_buf)?;
+
+        if !fields_buf.is_empty() {
+            return Err(alloy_rlp::Error::UnexpectedLength);
+        }
+
+        *buf = &buf[header.payload_length..];
+
+        Ok(Self { status, cumulative_gas_used, logs })
+    }
+}
+
 impl<T> From<ReceiptWithBloom<Self>> for Receipt<T> {
     /// Consume the structure, returning only the receipt
     fn from(receipt_with_bloom: ReceiptWithBloom<Self>) -> Self {
-        receipt_with_bloom.receipt
+        receipt_with_bloom.into_receipt()
     }
 }
 
+/// The maximum number of empty blocks that are filled in when assembling [`Receipts`] from block
+/// numbers or indices, e.g. in [`Receipts::from_block_results`].
+///
+/// This bounds the allocation for untrusted input with large gaps between blocks.
+pub const MAX_EMPTY_BLOCK_FILL: usize = 1 << 20;
+
 /// Receipt containing result of transaction execution.
 #[derive(
     Clone, Debug, PartialEq, Eq, Default, From, derive_more::Deref, DerefMut, IntoIterator,
@@ -125,133 +629,925 @@ impl<T> Receipts<T> {
     pub fn push(&mut self, receipts: Vec<T>) {
         self.receipt_vec.push(receipts);
     }
-}
 
-impl<T> From<Vec<T>> for Receipts<T> {
-    fn from(block_receipts: Vec<T>) -> Self {
-        Self { receipt_vec: vec![block_receipts] }
+    /// Returns a new single-block [`Receipts`] containing only the receipts of the given block,
+    /// or `None` if the block index is out of range.
+    pub fn block_as_receipts(&self, block: usize) -> Option<Self>
+    where
+        T: Clone,
+    {
+        self.receipt_vec.get(block).cloned().map(Into::into)
     }
-}
 
-impl<T> FromIterator<Vec<T>> for Receipts<T> {
-    fn from_iter<I: IntoIterator<Item = Vec<T>>>(iter: I) -> Self {
-        Self { receipt_vec: iter.into_iter().collect() }
+    /// Consumes the collection and returns all receipts in a single flat vector, along with the
+    /// block boundaries.
+    ///
+    /// The boundaries are the prefix sums of the block sizes, i.e. the receipts of block `i` are
+    /// `flat[boundaries[i - 1]..boundaries[i]]`, with the first block starting at `0`.
+    pub fn flatten_with_boundaries(self) -> (Vec<T>, Vec<usize>) {
+        let mut boundaries = Vec::with_capacity(self.receipt_vec.len());
+        let mut flat = Vec::with_capacity(self.receipt_vec.iter().map(Vec::len).sum());
+        for block in self.receipt_vec {
+            flat.extend(block);
+            boundaries.push(flat.len());
+        }
+        (flat, boundaries)
+    }
+
+    /// Reconstructs a [`Receipts`] collection from a flat vector of receipts and the block
+    /// boundaries returned by [`Receipts::flatten_with_boundaries`].
+    ///
+    /// Returns `None` if the boundaries are not monotonically non-decreasing or if the last
+    /// boundary does not match the number of receipts.
+    pub fn from_flat_with_boundaries(flat: Vec<T>, boundaries: &[usize]) -> Option<Self> {
+        if boundaries.windows(2).any(|w| w[0] > w[1])
+            || boundaries.last().copied().unwrap_or_default() != flat.len()
+        {
+            return None;
+        }
+
+        let mut flat = flat.into_iter();
+        let mut start = 0;
+        let receipt_vec = boundaries
+            .iter()
+            .map(|&end| {
+                let block = flat.by_ref().take(end - start).collect();
+                start = end;
+                block
+            })
+            .collect();
+        Some(Self { receipt_vec })
+    }
+
+    /// Assembles a [`Receipts`] collection from `(block_number, receipts)` pairs given in any
+    /// order, e.g. the results of a parallel backfill.
+    ///
+    /// The blocks are ordered by block number, starting with the lowest one, and missing blocks in
+    /// between are filled with empty receipt lists.
+    ///
+    /// Returns `None` if a block number occurs more than once, or if more than
+    /// [`MAX_EMPTY_BLOCK_FILL`] missing blocks would have to be filled in.
+    pub fn from_block_results(mut results: Vec<(u64, Vec<T>)>) -> Option<Self> {
+        results.sort_unstable_by_key(|(number, _)| *number);
+        if results.windows(2).any(|w| w[0].0 == w[1].0) {
+            return None;
+        }
+
+        let (Some(&(first, _)), Some(&(last, _))) = (results.first(), results.last()) else {
+            return Some(Self { receipt_vec: Vec::new() });
+        };
+        // Block numbers are distinct, so the span covers at least one block per result.
+        let len = usize::try_from(last - first).ok()?.checked_add(1)?;
+        if len - results.len() > MAX_EMPTY_BLOCK_FILL {
+            return None;
+        }
+
+        let mut receipt_vec = Vec::with_capacity(len);
+        for (number, receipts) in results {
+            // Cannot truncate, as `number - first <= last - first` fits in an `usize`.
+            receipt_vec.resize_with((number - first) as usize, Vec::new);
+            receipt_vec.push(receipts);
+        }
+        Some(Self { receipt_vec })
+    }
+
+    /// Returns the indices of the blocks whose receipts differ between `self` and `other`.
+    ///
+    /// Blocks missing from the shorter collection are treated as empty, so they only count as
+    /// changed if the other collection has receipts for them.
+    pub fn changed_blocks(&self, other: &Self) -> Vec<usize>
+    where
+        T: PartialEq,
+    {
+        let len = self.receipt_vec.len().max(other.receipt_vec.len());
+        (0..len)
+            .filter(|&i| {
+                let a = self.receipt_vec.get(i).map_or(&[][..], Vec::as_slice);
+                let b = other.receipt_vec.get(i).map_or(&[][..], Vec::as_slice);
+                a != b
+            })
+            .collect()
     }
 }
 
-/// [`Receipt`] with calculated bloom filter.
-///
-/// This convenience type allows us to lazily calculate the bloom filter for a
-/// receipt, similar to [`Sealed`].
-///
-/// [`Sealed`]: crate::Sealed
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
-#[doc(alias = "TransactionReceiptWithBloom", alias = "TxReceiptWithBloom")]
-pub struct ReceiptWithBloom<T = Receipt<Log>> {
-    #[cfg_attr(feature = "serde", serde(flatten))]
-    /// The receipt.
-    pub receipt: T,
-    /// The bloom filter.
-    pub logs_bloom: Bloom,
+/// Aggregate statistics of a [`Receipts`] collection, see [`Receipts::summary`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ReceiptsSummary {
+    /// The number of blocks.
+    pub blocks: usize,
+    /// The number of receipts.
+    pub receipts: usize,
+    /// The number of logs.
+    pub logs: usize,
+    /// The total gas used by all blocks.
+    pub gas_used: u128,
+    /// The number of receipts of failed transactions.
+    pub failed: usize,
 }
 
-impl<R> TxReceipt for ReceiptWithBloom<R>
-where
-    R: TxReceipt,
-{
-    type Log = R::Log;
+impl ReceiptsSummary {
+    /// Combines the statistics of two disjoint collections.
+    pub const fn merge(self, other: Self) -> Self {
+        Self {
+            blocks: self.blocks + other.blocks,
+            receipts: self.receipts + other.receipts,
+            logs: self.logs + other.logs,
+            gas_used: self.gas_used + other.gas_used,
+            failed: self.failed + other.failed,
+        }
+    }
+}
 
-    fn status_or_post_state(&self) -> Eip658Value {
-        self.receipt.status_or_post_state()
+impl<T: TxReceipt> Receipts<T> {
+    /// Returns the average number of logs emitted per receipt across all blocks.
+    ///
+    /// Returns `0.0` if the collection contains no receipts.
+    pub fn avg_logs_per_tx(&self) -> f64 {
+        let (logs, receipts) =
+            self.receipt_vec.iter().flatten().fold((0usize, 0usize), |(logs, receipts), r| {
+                (logs + r.logs().len(), receipts + 1)
+            });
+        if receipts == 0 {
+            return 0.0;
+        }
+        logs as f64 / receipts as f64
     }
 
-    fn status(&self) -> bool {
-        self.receipt.status()
+    /// Returns the fraction of the block's total gas that was consumed by transactions which
+    /// emitted at least one log.
+    ///
+    /// Returns `0.0` if the block is out of range, empty or consumed no gas.
+    pub fn log_gas_fraction(&self, block: usize) -> f64 {
+        let Some(receipts) = self.receipt_vec.get(block) else { return 0.0 };
+        let total = block_gas_used(receipts);
+        if total == 0 {
+            return 0.0;
+        }
+        let logged: u128 = receipts
+            .iter()
+            .zip(gas_used_per_tx(receipts))
+            .filter(|(receipt, _)| !receipt.logs().is_empty())
+            .map(|(_, gas)| gas)
+            .sum();
+        logged as f64 / total as f64
     }
 
-    fn bloom(&self) -> Bloom {
-        self.logs_bloom
+    /// Returns `true` if every receipt in the collection uses the [EIP-658] status
+    /// representation.
+    ///
+    /// Returns `false` for ranges mixing both representations and `true` for an empty collection.
+    ///
+    /// [EIP-658]: https://eips.ethereum.org/EIPS/eip-658
+    pub fn is_all_eip658(&self) -> bool {
+        self.receipt_vec.iter().flatten().all(|r| r.status_or_post_state().is_eip658())
     }
 
-    fn bloom_cheap(&self) -> Option<Bloom> {
-        Some(self.logs_bloom)
+    /// Returns `true` if every receipt in the collection uses the pre-[EIP-658] post-state
+    /// representation.
+    ///
+    /// Returns `false` for ranges mixing both representations and `true` for an empty collection.
+    ///
+    /// [EIP-658]: https://eips.ethereum.org/EIPS/eip-658
+    pub fn is_all_pre658(&self) -> bool {
+        self.receipt_vec.iter().flatten().all(|r| r.status_or_post_state().is_post_state())
     }
 
-    fn cumulative_gas_used(&self) -> u128 {
-        self.receipt.cumulative_gas_used()
+    /// Returns the bloom filter covering every receipt of every block in the collection.
+    ///
+    /// This is the coarsest pre-filter: if an address or topic is not contained in the returned
+    /// bloom, it was not logged anywhere in the range. Like any bloom filter it can yield false
+    /// positives. An empty collection yields a zero bloom.
+    pub fn range_bloom(&self) -> Bloom {
+        self.receipt_vec.iter().fold(Bloom::ZERO, |bloom, block| bloom | aggregate_bloom(block))
     }
 
-    fn logs(&self) -> &[Self::Log] {
-        self.receipt.logs()
+    /// Returns the total gas used by each block, i.e. the cumulative gas used of its last
+    /// receipt.
+    ///
+    /// Empty blocks report `0`.
+    pub fn block_gas_used_vec(&self) -> Vec<u128> {
+        self.receipt_vec.iter().map(|block| block_gas_used(block)).collect()
     }
-}
 
-impl<R> From<R> for ReceiptWithBloom<R>
-where
-    R: TxReceipt,
-{
-    fn from(receipt: R) -> Self {
-        let logs_bloom = receipt.bloom();
-        Self { logs_bloom, receipt }
+    /// Returns the number of blocks whose aggregated bloom may contain logs emitted by `address`.
+    ///
+    /// This is meant as a cheap selectivity estimate before scanning the blocks. Since bloom
+    /// filters can yield false positives, the result is an upper bound on the number of blocks
+    /// that actually contain a log from `address`.
+    ///
+    /// The estimate is only cheap if the receipts carry their bloom, e.g. [`ReceiptWithBloom`].
+    /// Otherwise the bloom of every receipt is computed from its logs, which costs more than
+    /// scanning the logs for `address`. For repeated queries over plain receipts, use the
+    /// precomputed blooms of [`IndexedReceipts`](crate::IndexedReceipts) instead.
+    pub fn count_candidate_blocks(&self, address: Address) -> usize {
+        let input = BloomInput::Raw(address.as_slice());
+        self.receipt_vec.iter().filter(|block| aggregate_bloom(block).contains_input(input)).count()
     }
-}
 
-impl<R> ReceiptWithBloom<R> {
-    /// Create new [ReceiptWithBloom]
-    pub const fn new(receipt: R, logs_bloom: Bloom) -> Self {
-        Self { receipt, logs_bloom }
+    /// Returns the logs emitted by the transaction at index `tx` of block `block`.
+    ///
+    /// Returns `None` if either index is out of range.
+    pub fn tx_logs(&self, block: usize, tx: usize) -> Option<&[T::Log]> {
+        self.receipt_vec.get(block)?.get(tx).map(TxReceipt::logs)
     }
 
-    /// Consume the structure, returning the receipt and the bloom filter
-    pub fn into_components(self) -> (R, Bloom) {
-        (self.receipt, self.logs_bloom)
+    /// Returns the number of logs emitted in the block per unit of gas used by the block, a rough
+    /// measure of event density.
+    ///
+    /// Returns `0.0` if the block is out of range or consumed no gas.
+    pub fn logs_per_gas(&self, block: usize) -> f64 {
+        let Some(receipts) = self.receipt_vec.get(block) else { return 0.0 };
+        let gas = block_gas_used(receipts);
+        if gas == 0 {
+            return 0.0;
+        }
+        let logs: usize = receipts.iter().map(|receipt| receipt.logs().len()).sum();
+        logs as f64 / gas as f64
     }
-}
 
-impl<R: RlpReceipt> Encodable for ReceiptWithBloom<R> {
-    fn encode(&self, out: &mut dyn BufMut) {
-        self.receipt.rlp_encode_with_bloom(self.logs_bloom, out);
+    /// Writes one CSV row per receipt with the columns `block`, `tx_index`, `status`, `gas_used`
+    /// and `log_count`, preceded by a header row.
+    ///
+    /// The status is written as `1` or `0`, see [`TxReceipt::status`] for pre-[EIP-658] receipts.
+    /// The gas used is the gas used by the individual transaction. All numbers are written in
+    /// decimal.
+    ///
+    /// [EIP-658]: https://eips.ethereum.org/EIPS/eip-658
+    #[cfg(feature = "std")]
+    pub fn write_csv<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
+        writeln!(w, "block,tx_index,status,gas_used,log_count")?;
+        for (block, receipts) in self.receipt_vec.iter().enumerate() {
+            for (tx, (receipt, gas_used)) in
+                receipts.iter().zip(gas_used_per_tx(receipts)).enumerate()
+            {
+                let status = u8::from(receipt.status());
+                let logs = receipt.logs().len();
+                writeln!(w, "{block},{tx},{status},{gas_used},{logs}")?;
+            }
+        }
+        Ok(())
     }
 
-    fn length(&self) -> usize {
-        self.receipt.rlp_encoded_length_with_bloom(self.logs_bloom)
+    /// Returns the aggregated bloom of every window of `window` consecutive blocks, in order of
+    /// their first block.
+    ///
+    /// For a collection of `n` blocks this yields `n - window + 1` blooms. If the window is larger
+    /// than the collection, a single bloom over all blocks is returned. A window of zero blocks
+    /// and an empty collection yield no blooms.
+    ///
+    /// Each block bloom is computed once, and the windows are combined from per-chunk prefix and
+    /// suffix accumulations, so the cost does not depend on the window size.
+    pub fn windowed_blooms(&self, window: usize) -> Vec<Bloom> {
+        let blooms: Vec<_> = self.receipt_vec.iter().map(|block| aggregate_bloom(block)).collect();
+        if window == 0 || blooms.is_empty() {
+            return Vec::new();
+        }
+        if window >= blooms.len() {
+            return vec![blooms.iter().fold(Bloom::ZERO, |acc, bloom| acc | *bloom)];
+        }
+
+        // Within each chunk of `window` blocks, `prefix[i]` accumulates from the chunk start up to
+        // `i`, and `suffix[i]` from `i` up to the chunk end. Every window spans at most two chunks.
+        let mut prefix = blooms.clone();
+        let mut suffix = blooms;
+        for i in 1..prefix.len() {
+            if i % window != 0 {
+                prefix[i] = prefix[i] | prefix[i - 1];
+            }
+        }
+        for i in (0..suffix.len() - 1).rev() {
+            if (i + 1) % window != 0 {
+                suffix[i] = suffix[i] | suffix[i + 1];
+            }
+        }
+        (0..=prefix.len() - window).map(|i| suffix[i] | prefix[i + window - 1]).collect()
     }
-}
 
-impl<R: RlpReceipt> Decodable for ReceiptWithBloom<R> {
-    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
-        R::rlp_decode_with_bloom(buf)
+    /// Returns the signed difference in the number of logs of each block of `self` compared to
+    /// `other`, for the blocks where it is non-zero.
+    ///
+    /// Blocks missing from the shorter collection count as having no logs.
+    pub fn log_count_delta(&self, other: &Self) -> Vec<(usize, isize)> {
+        let log_count =
+            |receipts: &Self, block: usize| {
+                receipts.receipt_vec.get(block).map_or(0, |block| {
+                    block.iter().map(|receipt| receipt.logs().len()).sum::<usize>()
+                }) as isize
+            };
+        let len = self.receipt_vec.len().max(other.receipt_vec.len());
+        (0..len)
+            .map(|block| (block, log_count(self, block) - log_count(other, block)))
+            .filter(|(_, delta)| *delta != 0)
+            .collect()
     }
-}
 
-#[cfg(any(test, feature = "arbitrary"))]
-impl<'a, R> arbitrary::Arbitrary<'a> for ReceiptWithBloom<R>
-where
-    R: arbitrary::Arbitrary<'a>,
-{
-    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
-        Ok(Self { receipt: R::arbitrary(u)?, logs_bloom: Bloom::arbitrary(u)? })
+    /// Returns aggregate statistics of the collection.
+    ///
+    /// The statistics are computed per block and combined with [`ReceiptsSummary::merge`].
+    pub fn summary(&self) -> ReceiptsSummary {
+        self.receipt_vec
+            .iter()
+            .map(|block| block_summary(block))
+            .fold(ReceiptsSummary::default(), ReceiptsSummary::merge)
     }
-}
 
-#[cfg(test)]
-mod test {
+    /// Returns the same statistics as [`Receipts::summary`], summarizing the blocks in parallel.
+    ///
+    /// Summarizing a block is cheap, so the fixed cost of the parallel reduction dominates small
+    /// collections. In the `summary` benchmark with 200 receipts per block on a single core, it
+    /// took 8.1µs instead of 2.0µs for 10 blocks, 30µs instead of 22µs for 100 blocks, and was
+    /// within 3% of [`Receipts::summary`] from 1000 blocks (200,000 receipts) on. Below that size,
+    /// prefer [`Receipts::summary`]. Above it, the speedup depends on the number of available
+    /// cores.
+    #[cfg(feature = "rayon")]
+    pub fn summary_par(&self) -> ReceiptsSummary
+    where
+        T: Sync,
+    {
+        use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
-    #[cfg(feature = "serde")]
-    #[test]
-    fn root_vs_status() {
-        let receipt = super::Receipt::<()> {
-            status: super::Eip658Value::Eip658(true),
-            cumulative_gas_used: 0,
-            logs: Vec::new(),
-        };
+        self.receipt_vec
+            .par_iter()
+            .map(|block| block_summary(block))
+            .reduce(ReceiptsSummary::default, ReceiptsSummary::merge)
+    }
 
-        let json = serde_json::to_string(&receipt).unwrap();
-        assert_eq!(json, r#"{"status":"0x1","cumulativeGasUsed":"0x0","logs":[]}"#);
+    /// Returns the fraction of all receipts that emitted at least one log.
+    ///
+    /// Returns `0.0` for a collection without receipts.
+    pub fn log_emitting_fraction(&self) -> f64 {
+        let (emitting, receipts) =
+            self.receipt_vec.iter().flatten().fold((0usize, 0usize), |(emitting, receipts), r| {
+                (emitting + !r.logs().is_empty() as usize, receipts + 1)
+            });
+        if receipts == 0 {
+            return 0.0;
+        }
+        emitting as f64 / receipts as f64
+    }
 
-        let receipt = super::Receipt::<()> {
+    /// Returns the total gas used by the blocks in the given range.
+    ///
+    /// The range is clamped to the blocks of the collection.
+    pub fn range_gas_used(&self, range: Range<usize>) -> u128 {
+        let end = range.end.min(self.receipt_vec.len());
+        let start = range.start.min(end);
+        self.receipt_vec[start..end].iter().map(|block| block_gas_used(block)).sum()
+    }
+
+    /// Splits the receipts into those of successful and of failed transactions, along with the
+    /// index of their block and of the receipt within the block.
+    ///
+    /// Both lists are in block-major order. Pre-[EIP-658] receipts are treated as successful, see
+    /// [`TxReceipt::status`].
+    ///
+    /// [EIP-658]: https://eips.ethereum.org/EIPS/eip-658
+    #[allow(clippy::type_complexity)]
+    pub fn partition_by_status(&self) -> (Vec<(usize, usize, &T)>, Vec<(usize, usize, &T)>) {
+        self.receipt_vec
+            .iter()
+            .enumerate()
+            .flat_map(|(block, receipts)| {
+                receipts.iter().enumerate().map(move |(tx, receipt)| (block, tx, receipt))
+            })
+            .partition(|(_, _, receipt)| receipt.status())
+    }
+
+    /// Returns the smallest and largest gas used by a single transaction of the given block.
+    ///
+    /// Returns `None` if the block is out of range or empty.
+    pub fn gas_used_minmax(&self, block: usize) -> Option<(u128, u128)> {
+        let mut gas_used = gas_used_per_tx(self.receipt_vec.get(block)?);
+        let first = gas_used.next()?;
+        Some(gas_used.fold((first, first), |(min, max), gas| (min.min(gas), max.max(gas))))
+    }
+
+    /// Returns a human readable summary line for each block, e.g.
+    /// `block 0: 5 txs, 3 failed, 12 logs, gas 1.2M`.
+    ///
+    /// This is meant for debugging and progress reporting, the format is not stable.
+    pub fn block_summaries(&self) -> Vec<String> {
+        use fmt::Write;
+
+        self.receipt_vec
+            .iter()
+            .enumerate()
+            .map(|(block, receipts)| {
+                let ReceiptsSummary { receipts, logs, gas_used, failed, .. } =
+                    block_summary(receipts);
+                let mut line = String::with_capacity(64);
+                let _ = write!(
+                    line,
+                    "block {block}: {receipts} txs, {failed} failed, {logs} logs, gas "
+                );
+                let _ = match gas_used {
+                    0..1_000 => write!(line, "{gas_used}"),
+                    1_000..1_000_000 => write!(line, "{:.1}K", gas_used as f64 / 1e3),
+                    1_000_000..1_000_000_000 => write!(line, "{:.1}M", gas_used as f64 / 1e6),
+                    _ => write!(line, "{:.1}G", gas_used as f64 / 1e9),
+                };
+                line
+            })
+            .collect()
+    }
+}
+
+impl<T> Receipts<T>
+where
+    T: TxReceipt,
+    T::Log: Borrow<Log>,
+{
+    /// Returns the `n` addresses that emitted the most logs across all blocks, along with their
+    /// log counts.
+    ///
+    /// The result is sorted by descending count, ties are broken by ascending address.
+    pub fn top_addresses(&self, n: usize) -> Vec<(Address, usize)> {
+        let mut counts = HashMap::<Address, usize>::default();
+        for log in self.receipt_vec.iter().flatten().flat_map(TxReceipt::logs) {
+            *counts.entry(log.borrow().address).or_default() += 1;
+        }
+
+        let mut ranked: Vec<_> = counts.into_iter().collect();
+        let cmp = |a: &(Address, usize), b: &(Address, usize)| b.1.cmp(&a.1).then(a.0.cmp(&b.0));
+        if n < ranked.len() {
+            if n == 0 {
+                return Vec::new();
+            }
+            ranked.select_nth_unstable_by(n - 1, cmp);
+            ranked.truncate(n);
+        }
+        ranked.sort_unstable_by(cmp);
+        ranked
+    }
+
+    /// Returns the number of distinct addresses that emitted at least one log in the given range
+    /// of blocks.
+    ///
+    /// The range is clamped to the blocks of the collection.
+    pub fn distinct_emitters(&self, range: Range<usize>) -> usize {
+        let end = range.end.min(self.receipt_vec.len());
+        let start = range.start.min(end);
+        self.receipt_vec[start..end]
+            .iter()
+            .flatten()
+            .flat_map(TxReceipt::logs)
+            .map(|log| log.borrow().address)
+            .collect::<BTreeSet<_>>()
+            .len()
+    }
+
+    /// Returns the index of the first block containing a log matching the filter.
+    ///
+    /// If all receipts of a block carry their bloom, e.g. [`ReceiptWithBloom`], the block is
+    /// skipped without inspecting its logs if its aggregated bloom cannot contain a match.
+    /// Otherwise the logs are scanned directly, as computing the bloom would cost more.
+    pub fn first_matching_block(&self, filter: &LogFilter) -> Option<usize> {
+        self.receipt_vec.iter().position(|block| {
+            cached_aggregate_bloom(block).map_or(true, |bloom| filter.matches_bloom(&bloom))
+                && block.iter().flat_map(TxReceipt::logs).any(|log| filter.matches(log.borrow()))
+        })
+    }
+
+    /// Returns the set of distinct event signatures, i.e. first topics, emitted in each block.
+    ///
+    /// Logs without topics are skipped.
+    pub fn topic0_sets_per_block(&self) -> Vec<BTreeSet<B256>> {
+        self.receipt_vec
+            .iter()
+            .map(|block| {
+                block
+                    .iter()
+                    .flat_map(TxReceipt::logs)
+                    .filter_map(|log| log.borrow().topics().first())
+                    .copied()
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the block and transaction indices of the receipts containing at least one log
+    /// emitted by `address`, in block-major order.
+    ///
+    /// If all receipts of a block carry their bloom, e.g. [`ReceiptWithBloom`], the block is
+    /// skipped without inspecting its logs if its aggregated bloom cannot contain the address.
+    /// Otherwise the logs are scanned directly, as computing the bloom would cost more.
+    pub fn receipts_touching(&self, address: Address) -> Vec<(usize, usize)> {
+        let input = BloomInput::Raw(address.as_slice());
+        self.receipt_vec
+            .iter()
+            .enumerate()
+            .filter(|(_, block)| {
+                cached_aggregate_bloom(block).map_or(true, |bloom| bloom.contains_input(input))
+            })
+            .flat_map(|(block, receipts)| {
+                receipts
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, receipt)| {
+                        receipt.logs().iter().any(|log| log.borrow().address == address)
+                    })
+                    .map(move |(tx, _)| (block, tx))
+            })
+            .collect()
+    }
+
+    /// Returns the indices of the blocks whose aggregated bloom may contain `address`, but which
+    /// contain no log emitted by it.
+    ///
+    /// This measures the false positive rate of bloom pre-filtering for a given workload, e.g.
+    /// compared to the result of [`Receipts::count_candidate_blocks`].
+    ///
+    /// The logs of each block are scanned first, so the bloom is only consulted for blocks
+    /// without a log from `address`. For receipts that do not carry their bloom, e.g. [`Receipt`],
+    /// it is computed from the logs of these blocks, which makes this a diagnostic rather than a
+    /// query to run on hot paths.
+    pub fn bloom_false_positives(&self, address: Address) -> Vec<usize> {
+        let input = BloomInput::Raw(address.as_slice());
+        self.receipt_vec
+            .iter()
+            .enumerate()
+            .filter(|(_, block)| {
+                !block.iter().flat_map(TxReceipt::logs).any(|log| log.borrow().address == address)
+                    && aggregate_bloom(block).contains_input(input)
+            })
+            .map(|(block, _)| block)
+            .collect()
+    }
+
+    /// Returns every log of the collection grouped by its emitting address, along with the index
+    /// of its block and of its receipt within the block.
+    ///
+    /// Within each group, logs are in block-major emission order. The index holds one entry per
+    /// log of the collection, so for large collections with many logs prefer bloom-prefiltered
+    /// queries such as [`Receipts::receipts_touching`].
+    pub fn logs_by_address_all(&self) -> HashMap<Address, Vec<(usize, usize, &Log)>> {
+        let mut index = HashMap::<_, Vec<_>>::default();
+        for (block, receipts) in self.receipt_vec.iter().enumerate() {
+            for (tx, receipt) in receipts.iter().enumerate() {
+                for log in receipt.logs().iter().map(Borrow::borrow) {
+                    index.entry(log.address).or_default().push((block, tx, log));
+                }
+            }
+        }
+        index
+    }
+}
+
+impl<R: RlpReceipt> Receipts<ReceiptWithBloom<R>> {
+    /// Returns the RLP list encoding of the receipts of the given block, e.g. for a network
+    /// response.
+    ///
+    /// Returns `None` if the block is out of range.
+    pub fn block_receipts_rlp(&self, block: usize) -> Option<Vec<u8>> {
+        let receipts = self.receipt_vec.get(block)?;
+        let mut out = Vec::with_capacity(alloy_rlp::list_length(receipts));
+        alloy_rlp::encode_list(receipts, &mut out);
+        Some(out)
+    }
+
+    /// Returns the length of [`Receipts::block_receipts_rlp`] for the given block, including the
+    /// list header, without encoding the receipts.
+    ///
+    /// Returns `None` if the block is out of range.
+    pub fn block_receipts_encoded_len(&self, block: usize) -> Option<usize> {
+        self.receipt_vec.get(block).map(|receipts| alloy_rlp::list_length(receipts))
+    }
+
+    /// Returns the receipts root of the given block, computing it only if it is not present in
+    /// `cache` yet.
+    ///
+    /// The cache is keyed by block index and owned by the caller, who controls its lifetime.
+    /// Returns `None` without touching the cache if the block is out of range.
+    pub fn root_memoized<S: BuildHasher>(
+        &self,
+        block: usize,
+        cache: &mut HashMap<usize, B256, S>,
+    ) -> Option<B256> {
+        let receipts = self.receipt_vec.get(block)?;
+        Some(*cache.entry(block).or_insert_with(|| crate::proofs::calculate_receipt_root(receipts)))
+    }
+}
+
+impl<R: TxReceipt> Receipts<ReceiptWithBloom<R>> {
+    /// Returns the indices of the blocks containing at least one receipt whose stored bloom filter
+    /// does not match its logs, see [`ReceiptWithBloom::verify_bloom`].
+    ///
+    /// This recalculates the bloom filter of every receipt.
+    pub fn blocks_with_stale_blooms(&self) -> Vec<usize> {
+        self.receipt_vec
+            .iter()
+            .enumerate()
+            .filter(|(_, block)| !block.iter().all(ReceiptWithBloom::verify_bloom))
+            .map(|(block, _)| block)
+            .collect()
+    }
+}
+
+/// Returns the total gas used by a block, i.e. the cumulative gas used of its last receipt.
+pub(crate) fn block_gas_used<R: TxReceipt>(receipts: &[R]) -> u128 {
+    receipts.last().map_or(0, TxReceipt::cumulative_gas_used)
+}
+
+/// Returns the statistics of a single block.
+fn block_summary<R: TxReceipt>(receipts: &[R]) -> ReceiptsSummary {
+    ReceiptsSummary {
+        blocks: 1,
+        receipts: receipts.len(),
+        logs: receipts.iter().map(|receipt| receipt.logs().len()).sum(),
+        gas_used: block_gas_used(receipts),
+        failed: receipts.iter().filter(|receipt| !receipt.status()).count(),
+    }
+}
+
+/// Returns the union of the bloom filters of the given receipts, using the cached bloom where
+/// available.
+pub(crate) fn aggregate_bloom<R: TxReceipt>(receipts: &[R]) -> Bloom {
+    receipts.iter().fold(Bloom::ZERO, |bloom, receipt| {
+        bloom | receipt.bloom_cheap().unwrap_or_else(|| receipt.bloom())
+    })
+}
+
+/// Returns the union of the cached bloom filters of the given receipts, or `None` if any receipt
+/// does not carry its bloom.
+///
+/// Bloom pre-filtering is only worth it with cached blooms, computing a bloom costs more than
+/// scanning the logs it is computed from.
+pub(crate) fn cached_aggregate_bloom<R: TxReceipt>(receipts: &[R]) -> Option<Bloom> {
+    receipts.iter().try_fold(Bloom::ZERO, |bloom, receipt| Some(bloom | receipt.bloom_cheap()?))
+}
+
+/// Returns an iterator over the gas used by each individual transaction, derived from the
+/// cumulative gas used of consecutive receipts of a single block.
+pub(crate) fn gas_used_per_tx<R: TxReceipt>(receipts: &[R]) -> impl Iterator<Item = u128> + '_ {
+    receipts.iter().scan(0u128, |prev, receipt| {
+        let cumulative = receipt.cumulative_gas_used();
+        let gas_used = cumulative.saturating_sub(*prev);
+        *prev = cumulative;
+        Some(gas_used)
+    })
+}
+
+impl<T> From<Vec<T>> for Receipts<T> {
+    fn from(block_receipts: Vec<T>) -> Self {
+        Self { receipt_vec: vec![block_receipts] }
+    }
+}
+
+impl<T> FromIterator<Vec<T>> for Receipts<T> {
+    fn from_iter<I: IntoIterator<Item = Vec<T>>>(iter: I) -> Self {
+        Self { receipt_vec: iter.into_iter().collect() }
+    }
+}
+
+/// [`Receipt`] with calculated bloom filter.
+///
+/// This convenience type allows us to lazily calculate the bloom filter for a
+/// receipt, similar to [`Sealed`].
+///
+/// [`Sealed`]: crate::Sealed
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[doc(alias = "TransactionReceiptWithBloom", alias = "TxReceiptWithBloom")]
+pub struct ReceiptWithBloom<T = Receipt<Log>> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    /// The receipt.
+    pub receipt: T,
+    /// The bloom filter.
+    pub logs_bloom: Bloom,
+}
+
+impl<R> TxReceipt for ReceiptWithBloom<R>
+where
+    R: TxReceipt,
+{
+    type Log = R::Log;
+
+    fn status_or_post_state(&self) -> Eip658Value {
+        self.receipt.status_or_post_state()
+    }
+
+    fn status(&self) -> bool {
+        self.receipt.status()
+    }
+
+    fn bloom(&self) -> Bloom {
+        self.logs_bloom
+    }
+
+    fn bloom_cheap(&self) -> Option<Bloom> {
+        Some(self.logs_bloom)
+    }
+
+    fn cumulative_gas_used(&self) -> u128 {
+        self.receipt.cumulative_gas_used()
+    }
+
+    fn logs(&self) -> &[Self::Log] {
+        self.receipt.logs()
+    }
+}
+
+impl<R> From<R> for ReceiptWithBloom<R>
+where
+    R: TxReceipt,
+{
+    fn from(receipt: R) -> Self {
+        let logs_bloom = receipt.bloom();
+        Self { logs_bloom, receipt }
+    }
+}
+
+impl<R> ReceiptWithBloom<R> {
+    /// Create new [ReceiptWithBloom]
+    ///
+    /// The bloom filter is not checked against the logs of the receipt, see
+    /// [`ReceiptWithBloom::new_validated`] for untrusted inputs.
+    pub const fn new(receipt: R, logs_bloom: Bloom) -> Self {
+        Self { receipt, logs_bloom }
+    }
+
+    /// Consume the structure, returning the receipt and the bloom filter
+    pub fn into_components(self) -> (R, Bloom) {
+        (self.receipt, self.logs_bloom)
+    }
+
+    /// Consume the structure, returning only the receipt
+    pub fn into_receipt(self) -> R {
+        self.receipt
+    }
+}
+
+impl<R: TxReceipt> ReceiptWithBloom<R> {
+    /// Creates a new [`ReceiptWithBloom`], checking that the bloom filter matches the logs of the
+    /// receipt.
+    ///
+    /// This recalculates the bloom filter, use [`ReceiptWithBloom::new`] for trusted inputs.
+    pub fn new_validated(receipt: R, logs_bloom: Bloom) -> Result<Self, ReceiptError> {
+        if receipt.bloom() != logs_bloom {
+            return Err(ReceiptError::BloomMismatch);
+        }
+        Ok(Self { receipt, logs_bloom })
+    }
+
+    /// Recalculates the stored bloom filter from the logs of the receipt, e.g. after editing them
+    /// through [`Receipt::retain_logs`].
+    pub fn recompute_bloom(&mut self) {
+        self.logs_bloom = self.receipt.bloom();
+    }
+
+    /// Returns `true` if the stored bloom filter matches the logs of the receipt.
+    pub fn verify_bloom(&self) -> bool {
+        self.logs_bloom == self.receipt.bloom()
+    }
+
+    /// Asserts that the stored bloom filter matches the logs of the receipt.
+    ///
+    /// This catches stale blooms after editing the logs of the receipt during development, and is
+    /// a no-op unless debug assertions are enabled. See [`ReceiptWithBloom::recompute_bloom`] to
+    /// fix a stale bloom.
+    #[track_caller]
+    pub fn assert_bloom_fresh(&self) {
+        if cfg!(debug_assertions) {
+            assert!(
+                self.verify_bloom(),
+                "stale receipt bloom, call `recompute_bloom` after editing the logs"
+            );
+        }
+    }
+}
+
+/// Error returned when constructing a receipt from inconsistent components.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReceiptError {
+    /// The bloom filter does not match the logs of the receipt.
+    BloomMismatch,
+    /// The number of log emission indices does not match the number of logs.
+    LogOrderLengthMismatch {
+        /// The number of logs.
+        logs: usize,
+        /// The number of emission indices.
+        indices: usize,
+    },
+    /// A log emission index occurs more than once.
+    DuplicateEmissionIndex(u64),
+}
+
+impl fmt::Display for ReceiptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BloomMismatch => f.write_str("receipt bloom mismatch"),
+            Self::LogOrderLengthMismatch { logs, indices } => {
+                write!(f, "expected one emission index per log, got {indices} for {logs} logs")
+            }
+            Self::DuplicateEmissionIndex(index) => {
+                write!(f, "duplicate log emission index {index}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ReceiptError {}
+
+/// Options controlling the serialization of a [`ReceiptWithBloom`], see
+/// [`ReceiptWithBloom::serialize_with`].
+#[cfg(feature = "serde")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReceiptSerializeOptions {
+    /// Whether to emit the `logsBloom` field. Defaults to `true`.
+    pub include_bloom: bool,
+}
+
+#[cfg(feature = "serde")]
+impl Default for ReceiptSerializeOptions {
+    fn default() -> Self {
+        Self { include_bloom: true }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<R: serde::Serialize> ReceiptWithBloom<R> {
+    /// Serializes the receipt with the given options.
+    ///
+    /// With the default options, this is the same as the [`serde::Serialize`] implementation.
+    /// Excluding the bloom saves bandwidth for clients that do not need it.
+    pub fn serialize_with<S: serde::Serializer>(
+        &self,
+        opts: &ReceiptSerializeOptions,
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        #[derive(serde::Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Helper<'a, R> {
+            #[serde(flatten)]
+            receipt: &'a R,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            logs_bloom: Option<&'a Bloom>,
+        }
+
+        let logs_bloom = opts.include_bloom.then_some(&self.logs_bloom);
+        serde::Serialize::serialize(&Helper { receipt: &self.receipt, logs_bloom }, s)
+    }
+}
+
+impl<R: RlpReceipt> ReceiptWithBloom<R> {
+    /// RLP encodes the receipt, returning the encoding along with its keccak256 hash.
+    ///
+    /// The encoding can be stored as is and later passed to
+    /// [`calculate_receipt_root_from_encoded`](crate::proofs::calculate_receipt_root_from_encoded)
+    /// to compute the receipt root without encoding the receipts a second time.
+    pub fn encode_and_cache(&self) -> (Vec<u8>, B256) {
+        let mut encoded = Vec::with_capacity(self.length());
+        self.encode(&mut encoded);
+        let hash = keccak256(&encoded);
+        (encoded, hash)
+    }
+}
+
+impl<R: RlpReceipt> Encodable for ReceiptWithBloom<R> {
+    fn encode(&self, out: &mut dyn BufMut) {
+        self.receipt.rlp_encode_with_bloom(self.logs_bloom, out);
+    }
+
+    fn length(&self) -> usize {
+        self.receipt.rlp_encoded_length_with_bloom(self.logs_bloom)
+    }
+}
+
+impl<R: RlpReceipt> Decodable for ReceiptWithBloom<R> {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        R::rlp_decode_with_bloom(buf)
+    }
+}
+
+#[cfg(any(test, feature = "arbitrary"))]
+impl<'a, R> arbitrary::Arbitrary<'a> for ReceiptWithBloom<R>
+where
+    R: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self { receipt: R::arbitrary(u)?, logs_bloom: Bloom::arbitrary(u)? })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::receipt::test_utils::{log, log_with_topics, receipt, receipt_with};
+    use alloy_primitives::{map::HashMap, Address, Bloom, Bytes, Log, LogData, B256, U256};
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn root_vs_status() {
+        let receipt = super::Receipt::<()> {
+            status: super::Eip658Value::Eip658(true),
+            cumulative_gas_used: 0,
+            logs: Vec::new(),
+        };
+
+        let json = serde_json::to_string(&receipt).unwrap();
+        assert_eq!(json, r#"{"status":"0x1","cumulativeGasUsed":"0x0","logs":[]}"#);
+
+        let receipt = super::Receipt::<()> {
             status: super::Eip658Value::PostState(Default::default()),
             cumulative_gas_used: 0,
             logs: Vec::new(),
@@ -280,4 +1576,877 @@ mod test {
             ))
         );
     }
+
+    #[test]
+    fn top_addresses() {
+        let receipts = super::Receipts {
+            receipt_vec: vec![
+                vec![receipt(vec![log(3), log(1)]), receipt(vec![log(2)])],
+                vec![receipt(vec![log(3), log(2), log(4)])],
+            ],
+        };
+
+        assert_eq!(
+            receipts.top_addresses(3),
+            vec![
+                (Address::repeat_byte(2), 2),
+                (Address::repeat_byte(3), 2),
+                (Address::repeat_byte(1), 1)
+            ]
+        );
+        assert_eq!(receipts.top_addresses(10).len(), 4);
+        assert!(receipts.top_addresses(0).is_empty());
+    }
+
+    #[test]
+    fn flat_with_boundaries_roundtrip() {
+        let receipts = super::Receipts { receipt_vec: vec![vec![1, 2], vec![], vec![3]] };
+
+        let (flat, boundaries) = receipts.clone().flatten_with_boundaries();
+        assert_eq!(flat, vec![1, 2, 3]);
+        assert_eq!(boundaries, vec![2, 2, 3]);
+        assert_eq!(super::Receipts::from_flat_with_boundaries(flat, &boundaries), Some(receipts));
+
+        assert_eq!(super::Receipts::from_flat_with_boundaries(vec![1, 2, 3], &[2, 1, 3]), None);
+        assert_eq!(super::Receipts::from_flat_with_boundaries(vec![1, 2, 3], &[1, 2]), None);
+    }
+
+    #[test]
+    fn eq_logs_unordered() {
+        let a = receipt(vec![log(1), log(2), log(2)]);
+        assert!(a.eq_logs_unordered(&receipt(vec![log(2), log(1), log(2)])));
+        assert!(!a.eq_logs_unordered(&receipt(vec![log(1), log(1), log(2)])));
+        assert!(!a.eq_logs_unordered(&receipt(vec![log(1), log(2)])));
+
+        let mut b = receipt(vec![log(2), log(2), log(1)]);
+        b.cumulative_gas_used = 2;
+        assert!(!a.eq_logs_unordered(&b));
+    }
+
+    #[cfg(all(feature = "serde", feature = "json-core"))]
+    #[test]
+    fn json_core_matches_serde() {
+        use alloy_primitives::{address, b256, bytes, Log, LogData};
+
+        let receipts = [
+            super::Receipt {
+                status: true.into(),
+                cumulative_gas_used: 0x5208,
+                logs: vec![Log {
+                    address: address!("0000000000000000000000000000000000000011"),
+                    data: LogData::new_unchecked(
+                        vec![b256!(
+                            "000000000000000000000000000000000000000000000000000000000000dead"
+                        )],
+                        bytes!("0100ff"),
+                    ),
+                }],
+            },
+            super::Receipt {
+                status: super::Eip658Value::PostState(b256!(
+                    "284d35bf53b82ef480ab4208527325477439c64fb90ef518450f05ee151c8e10"
+                )),
+                cumulative_gas_used: 0,
+                logs: vec![Log::default(), Log::default()],
+            },
+        ];
+
+        for receipt in receipts {
+            let mut json = String::new();
+            receipt.write_json_core(&mut json).unwrap();
+            assert_eq!(json, serde_json::to_string(&receipt).unwrap());
+        }
+    }
+
+    #[test]
+    fn count_candidate_blocks() {
+        let receipts = super::Receipts {
+            receipt_vec: vec![
+                vec![receipt(vec![log(1)]), receipt(vec![log(2)])],
+                vec![],
+                vec![receipt(vec![log(1), log(3)])],
+            ],
+        };
+
+        assert_eq!(receipts.count_candidate_blocks(Address::repeat_byte(1)), 2);
+        assert_eq!(receipts.count_candidate_blocks(Address::repeat_byte(2)), 1);
+        assert_eq!(receipts.count_candidate_blocks(Address::repeat_byte(9)), 0);
+    }
+
+    #[test]
+    fn from_block_results() {
+        let receipts = super::Receipts::from_block_results(vec![(12, vec![3]), (10, vec![1, 2])]);
+        assert_eq!(
+            receipts,
+            Some(super::Receipts { receipt_vec: vec![vec![1, 2], vec![], vec![3]] })
+        );
+
+        assert_eq!(super::Receipts::<u8>::from_block_results(vec![]), Some(Default::default()));
+        assert_eq!(super::Receipts::from_block_results(vec![(1, vec![1]), (1, vec![2])]), None);
+
+        let max = super::MAX_EMPTY_BLOCK_FILL as u64;
+        let receipts = super::Receipts::from_block_results(vec![(0, vec![1]), (max + 1, vec![2])]);
+        assert_eq!(receipts.map(|receipts| receipts.len()), Some(super::MAX_EMPTY_BLOCK_FILL + 2));
+        assert_eq!(
+            super::Receipts::from_block_results(vec![(0, vec![1]), (max + 2, vec![2])]),
+            None
+        );
+        assert_eq!(
+            super::Receipts::from_block_results(vec![(0, vec![1]), (u64::MAX, vec![2])]),
+            None
+        );
+    }
+
+    #[test]
+    fn partial_bloom() {
+        let receipt = receipt(vec![log(1), log(2), log(1)]);
+
+        let bloom = receipt.partial_bloom(|log| log.address == Address::repeat_byte(1));
+        assert_eq!(bloom, super::Receipt { logs: vec![log(1)], ..Default::default() }.bloom_slow());
+        assert_eq!(receipt.partial_bloom(|_| true), receipt.bloom_slow());
+        assert_eq!(receipt.partial_bloom(|_| false), Bloom::ZERO);
+    }
+
+    #[test]
+    fn cache_key() {
+        let key = receipt(vec![log(1)]).cache_key();
+        assert_eq!(key, receipt(vec![log(1)]).cache_key());
+        assert_ne!(
+            key,
+            super::Receipt { status: false.into(), ..receipt(vec![log(1)]) }.cache_key()
+        );
+        assert_ne!(key, receipt(vec![log(2)]).cache_key());
+        assert_ne!(key, receipt(vec![log(1), log(1)]).cache_key());
+
+        let receipt = receipt(vec![log(1)]);
+        let mut buf = Vec::new();
+        receipt.rlp_encode_without_bloom(&mut buf);
+        assert_eq!(buf.len(), receipt.rlp_encoded_length_without_bloom());
+        assert_eq!(super::Receipt::rlp_decode_without_bloom(&mut buf.as_slice()), Ok(receipt));
+    }
+
+    #[test]
+    fn tx_logs() {
+        let receipts = super::Receipts {
+            receipt_vec: vec![
+                vec![receipt(vec![])],
+                vec![receipt(vec![log(1)]), receipt(vec![log(2)])],
+            ],
+        };
+
+        assert_eq!(receipts.tx_logs(1, 1), Some(&[log(2)][..]));
+        assert_eq!(receipts.tx_logs(0, 0), Some(&[][..]));
+        assert_eq!(receipts.tx_logs(0, 1), None);
+        assert_eq!(receipts.tx_logs(2, 0), None);
+    }
+
+    #[test]
+    fn logs_grouped_by_address() {
+        let topic_log = |address, topic| log_with_topics(address, vec![B256::repeat_byte(topic)]);
+        let receipt = receipt(vec![topic_log(2, 1), topic_log(1, 2), topic_log(2, 3)]);
+
+        let groups = receipt.logs_grouped_by_address();
+        assert_eq!(
+            groups.into_iter().collect::<Vec<_>>(),
+            vec![
+                (Address::repeat_byte(1), vec![&receipt.logs[1]]),
+                (Address::repeat_byte(2), vec![&receipt.logs[0], &receipt.logs[2]]),
+            ]
+        );
+    }
+
+    #[test]
+    fn logs_per_gas() {
+        let receipts = super::Receipts {
+            receipt_vec: vec![
+                vec![receipt_with(true, 100, 1), receipt_with(true, 400, 2)],
+                vec![receipt_with(true, 0, 0)],
+            ],
+        };
+
+        assert_eq!(receipts.logs_per_gas(0), 3.0 / 400.0);
+        assert_eq!(receipts.logs_per_gas(1), 0.0);
+        assert_eq!(receipts.logs_per_gas(2), 0.0);
+    }
+
+    #[test]
+    fn changed_blocks() {
+        let a = super::Receipts { receipt_vec: vec![vec![1], vec![2], vec![3]] };
+        let b = super::Receipts { receipt_vec: vec![vec![1], vec![4], vec![3], vec![], vec![5]] };
+
+        assert_eq!(a.changed_blocks(&b), vec![1, 4]);
+        assert_eq!(b.changed_blocks(&a), vec![1, 4]);
+        assert!(a.changed_blocks(&a).is_empty());
+    }
+
+    #[test]
+    fn log_index_in_receipt() {
+        let receipt = receipt(vec![log(1), log(2), log(2)]);
+
+        assert_eq!(receipt.log_index_in_receipt(&log(1)), Some(0));
+        assert_eq!(receipt.log_index_in_receipt(&log(2)), Some(1));
+        assert_eq!(receipt.log_index_in_receipt(&log(3)), None);
+    }
+
+    #[cfg(feature = "bloom-cache")]
+    #[test]
+    fn bloom_cached() {
+        let receipt = receipt(
+            (0..100).map(|i| log_with_topics(i % 3, vec![B256::repeat_byte(i % 2)])).collect(),
+        );
+
+        assert_eq!(receipt.bloom_cached(), receipt.bloom_slow());
+        assert_eq!(super::Receipt::<Log>::default().bloom_cached(), Bloom::ZERO);
+    }
+
+    #[test]
+    fn write_csv() {
+        let receipts = super::Receipts {
+            receipt_vec: vec![
+                vec![receipt_with(true, 21_000, 2), receipt_with(false, 50_000, 0)],
+                vec![],
+                vec![receipt_with(true, 30_000, 0)],
+            ],
+        };
+
+        let mut out = Vec::new();
+        receipts.write_csv(&mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "block,tx_index,status,gas_used,log_count\n0,0,1,21000,2\n0,1,0,29000,0\n2,0,1,30000,0\n"
+        );
+    }
+
+    #[test]
+    fn into_receipt() {
+        let receipt = super::Receipt::<Log>::default();
+        let with_bloom = receipt.clone().with_bloom();
+        assert_eq!(with_bloom.clone().into_receipt(), receipt);
+        assert_eq!(super::Receipt::from(with_bloom), receipt);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deser_missing_logs() {
+        let json = r#"{"status":"0x1","cumulativeGasUsed":"0x5208"}"#;
+        let receipt: super::Receipt = serde_json::from_str(json).unwrap();
+        assert!(receipt.logs.is_empty());
+        assert_eq!(
+            serde_json::to_string(&receipt).unwrap(),
+            r#"{"status":"0x1","cumulativeGasUsed":"0x5208","logs":[]}"#
+        );
+
+        let json = r#"{"status":"0x1","cumulativeGasUsed":"0x5208","logs":[]}"#;
+        assert_eq!(serde_json::from_str::<super::Receipt>(json).unwrap(), receipt);
+    }
+
+    #[test]
+    fn block_receipts_rlp() {
+        let with_gas =
+            |cumulative_gas_used| receipt_with(true, cumulative_gas_used, 0).with_bloom();
+        let receipts =
+            super::Receipts { receipt_vec: vec![vec![with_gas(1), with_gas(2)], vec![]] };
+
+        let rlp = receipts.block_receipts_rlp(0).unwrap();
+        let decoded: Vec<super::ReceiptWithBloom> =
+            alloy_rlp::Decodable::decode(&mut rlp.as_slice()).unwrap();
+        assert_eq!(decoded, receipts[0]);
+        assert_eq!(receipts.block_receipts_rlp(1), Some(vec![alloy_rlp::EMPTY_LIST_CODE]));
+        assert_eq!(receipts.block_receipts_rlp(2), None);
+    }
+
+    #[test]
+    fn short_summary() {
+        let mut receipt =
+            super::Receipt { status: true.into(), cumulative_gas_used: 21_000, logs: vec![(); 3] };
+        assert_eq!(receipt.short_summary(), "success gas=21000 logs=3");
+
+        receipt.status = false.into();
+        receipt.logs.clear();
+        assert_eq!(receipt.short_summary(), "fail gas=21000 logs=0");
+    }
+
+    #[test]
+    fn from_call_result() {
+        let (receipt, bloom) = super::Receipt::from_call_result(false, 21_000, vec![log(0)]);
+
+        assert!(!receipt.status.coerce_status());
+        assert_eq!(receipt.cumulative_gas_used, 21_000);
+        assert_eq!(bloom, receipt.bloom_slow());
+    }
+
+    #[test]
+    fn distinct_emitters() {
+        let receipts = super::Receipts {
+            receipt_vec: vec![
+                vec![receipt(vec![log(1), log(2)])],
+                vec![receipt(vec![log(2)]), receipt(vec![log(3)])],
+                vec![receipt(vec![log(4)])],
+            ],
+        };
+
+        assert_eq!(receipts.distinct_emitters(0..3), 4);
+        assert_eq!(receipts.distinct_emitters(1..2), 2);
+        assert_eq!(receipts.distinct_emitters(1..100), 3);
+        assert_eq!(receipts.distinct_emitters(5..10), 0);
+    }
+
+    #[test]
+    fn validate_topic_arities() {
+        let transfer = B256::repeat_byte(1);
+        let receipt = receipt(vec![
+            log_with_topics(0, vec![transfer, B256::ZERO, B256::ZERO]),
+            log_with_topics(0, vec![transfer, B256::ZERO]),
+            log_with_topics(0, vec![B256::repeat_byte(2)]),
+            log(0),
+        ]);
+
+        let expected: HashMap<_, _> = [(transfer, 3)].into_iter().collect();
+        assert_eq!(receipt.validate_topic_arities(&expected), vec![1]);
+
+        // Any hasher can be used.
+        let mut expected = HashMap::with_hasher(std::hash::RandomState::new());
+        expected.insert(transfer, 3);
+        assert_eq!(receipt.validate_topic_arities(&expected), vec![1]);
+    }
+
+    #[test]
+    fn windowed_blooms() {
+        let receipts = super::Receipts {
+            receipt_vec: (0..7).map(|byte| vec![receipt(vec![log(byte)])]).collect(),
+        };
+        let blooms: Vec<Bloom> = receipts.iter().map(|block| block[0].bloom_slow()).collect();
+
+        for window in 1..=7 {
+            let expected: Vec<Bloom> = blooms
+                .windows(window)
+                .map(|w| w.iter().fold(Bloom::ZERO, |acc, bloom| acc | *bloom))
+                .collect();
+            assert_eq!(receipts.windowed_blooms(window), expected, "window {window}");
+        }
+        assert_eq!(receipts.windowed_blooms(10), receipts.windowed_blooms(7));
+        assert!(receipts.windowed_blooms(0).is_empty());
+    }
+
+    #[test]
+    fn first_matching_block() {
+        let receipts = super::Receipts {
+            receipt_vec: vec![
+                vec![receipt(vec![log(1)])],
+                vec![],
+                vec![receipt(vec![log(2)]), receipt(vec![log(3)])],
+                vec![receipt(vec![log(3)])],
+            ],
+        };
+
+        let filter = |byte| super::LogFilter::new().address(Address::repeat_byte(byte));
+        assert_eq!(receipts.first_matching_block(&filter(3)), Some(2));
+        assert_eq!(receipts.first_matching_block(&filter(1)), Some(0));
+        assert_eq!(receipts.first_matching_block(&filter(9)), None);
+
+        // With cached blooms, a saturated bloom is a candidate but still needs a matching log.
+        let saturated =
+            super::ReceiptWithBloom::new(receipt(vec![log(2)]), Bloom::repeat_byte(0xff));
+        let receipts = super::Receipts {
+            receipt_vec: vec![
+                vec![saturated],
+                vec![receipt(vec![log(1)]).with_bloom()],
+                vec![receipt(vec![log(3)]).with_bloom()],
+            ],
+        };
+        assert_eq!(receipts.first_matching_block(&filter(3)), Some(2));
+        assert_eq!(receipts.first_matching_block(&filter(2)), Some(0));
+        assert_eq!(receipts.first_matching_block(&filter(9)), None);
+    }
+
+    #[test]
+    fn topic0_sets_per_block() {
+        let event = |topics| log_with_topics(0, topics);
+        let (a, b) = (B256::repeat_byte(1), B256::repeat_byte(2));
+        let receipts = super::Receipts {
+            receipt_vec: vec![
+                vec![
+                    receipt(vec![event(vec![b, a]), event(vec![])]),
+                    receipt(vec![event(vec![a]), event(vec![b])]),
+                ],
+                vec![],
+            ],
+        };
+
+        assert_eq!(receipts.topic0_sets_per_block(), vec![[a, b].into(), Default::default()]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_with_options() {
+        let receipt = super::Receipt::<Log>::default().with_bloom();
+
+        let mut json = Vec::new();
+        let opts = super::ReceiptSerializeOptions::default();
+        receipt.serialize_with(&opts, &mut serde_json::Serializer::new(&mut json)).unwrap();
+        assert_eq!(json, serde_json::to_vec(&receipt).unwrap());
+
+        let mut json = Vec::new();
+        let opts = super::ReceiptSerializeOptions { include_bloom: false };
+        receipt.serialize_with(&opts, &mut serde_json::Serializer::new(&mut json)).unwrap();
+        assert_eq!(
+            String::from_utf8(json).unwrap(),
+            r#"{"status":"0x1","cumulativeGasUsed":"0x0","logs":[]}"#
+        );
+    }
+
+    #[test]
+    fn log_count_delta() {
+        let with_logs = |n| receipt_with(true, 1, n);
+        let a = super::Receipts {
+            receipt_vec: vec![vec![with_logs(2)], vec![with_logs(1)], vec![with_logs(3)]],
+        };
+        let b = super::Receipts { receipt_vec: vec![vec![with_logs(2)], vec![with_logs(4)]] };
+
+        assert_eq!(a.log_count_delta(&b), vec![(1, -3), (2, 3)]);
+        assert_eq!(b.log_count_delta(&a), vec![(1, 3), (2, -3)]);
+        assert!(a.log_count_delta(&a).is_empty());
+    }
+
+    #[test]
+    fn from_execution() {
+        let receipt = super::Receipt::from_execution(true, 1, vec!['a', 'b'], Default::default());
+        assert_eq!(receipt.unwrap().logs, vec!['a', 'b']);
+
+        let order = super::LogOrder::ByEmissionIndex(vec![7, 3, 5]);
+        let receipt = super::Receipt::from_execution(true, 1, vec!['c', 'a', 'b'], order);
+        assert_eq!(receipt.unwrap().logs, vec!['a', 'b', 'c']);
+
+        let order = super::LogOrder::ByEmissionIndex(vec![1, 1]);
+        assert_eq!(
+            super::Receipt::from_execution(true, 1, vec!['a', 'b'], order),
+            Err(super::ReceiptError::DuplicateEmissionIndex(1))
+        );
+
+        let order = super::LogOrder::ByEmissionIndex(vec![1]);
+        assert_eq!(
+            super::Receipt::from_execution(true, 1, vec!['a', 'b'], order),
+            Err(super::ReceiptError::LogOrderLengthMismatch { logs: 2, indices: 1 })
+        );
+    }
+
+    #[test]
+    fn receipts_touching() {
+        let receipts = super::Receipts {
+            receipt_vec: vec![
+                vec![receipt(vec![log(1)]), receipt(vec![log(2)]), receipt(vec![log(2), log(1)])],
+                vec![],
+                vec![receipt(vec![log(1)])],
+            ],
+        };
+
+        assert_eq!(
+            receipts.receipts_touching(Address::repeat_byte(1)),
+            vec![(0, 0), (0, 2), (2, 0)]
+        );
+        assert_eq!(receipts.receipts_touching(Address::repeat_byte(2)), vec![(0, 1), (0, 2)]);
+        assert!(receipts.receipts_touching(Address::repeat_byte(3)).is_empty());
+
+        let receipts = super::Receipts {
+            receipt_vec: receipts
+                .receipt_vec
+                .into_iter()
+                .map(|block| block.into_iter().map(super::Receipt::with_bloom).collect())
+                .collect(),
+        };
+        assert_eq!(receipts.receipts_touching(Address::repeat_byte(2)), vec![(0, 1), (0, 2)]);
+        assert!(receipts.receipts_touching(Address::repeat_byte(3)).is_empty());
+    }
+
+    #[test]
+    fn bloom_false_positives() {
+        let saturated = super::ReceiptWithBloom {
+            receipt: receipt(vec![log(2)]),
+            logs_bloom: Bloom::repeat_byte(0xff),
+        };
+        let receipts = super::Receipts {
+            receipt_vec: vec![
+                vec![receipt(vec![log(1)]).with_bloom()],
+                vec![saturated.clone()],
+                vec![receipt(vec![log(2)]).with_bloom()],
+                vec![saturated, receipt(vec![log(1)]).with_bloom()],
+            ],
+        };
+
+        assert_eq!(receipts.bloom_false_positives(Address::repeat_byte(1)), vec![1]);
+        assert_eq!(receipts.bloom_false_positives(Address::repeat_byte(3)), vec![1, 3]);
+    }
+
+    #[test]
+    fn canonical_hash_unordered() {
+        let distinct_log = |byte| log_with_topics(byte, vec![B256::repeat_byte(byte)]);
+
+        let a = receipt(vec![distinct_log(1), distinct_log(2), distinct_log(3)]);
+        let b = receipt(vec![distinct_log(3), distinct_log(1), distinct_log(2)]);
+        assert_ne!(a.cache_key(), b.cache_key());
+        assert_eq!(a.canonical_hash_unordered(), b.canonical_hash_unordered());
+        assert_ne!(
+            a.canonical_hash_unordered(),
+            receipt(vec![distinct_log(1), distinct_log(2)]).canonical_hash_unordered()
+        );
+    }
+
+    #[test]
+    fn find_event() {
+        let signature = B256::repeat_byte(0xaa);
+        let receipt = receipt(vec![
+            log(1),
+            log_with_topics(2, vec![B256::ZERO, signature]),
+            log_with_topics(3, vec![signature]),
+            log_with_topics(4, vec![signature]),
+        ]);
+
+        assert_eq!(
+            receipt.find_event(signature).map(|log| log.address),
+            Some(Address::repeat_byte(3))
+        );
+        assert_eq!(receipt.find_event(B256::repeat_byte(0xbb)), None);
+    }
+
+    #[test]
+    fn summary() {
+        let receipts = super::Receipts {
+            receipt_vec: vec![
+                vec![receipt_with(true, 21_000, 1), receipt_with(false, 50_000, 0)],
+                vec![],
+                vec![receipt_with(true, 30_000, 3)],
+            ],
+        };
+
+        assert_eq!(
+            receipts.summary(),
+            super::ReceiptsSummary { blocks: 3, receipts: 3, logs: 4, gas_used: 80_000, failed: 1 }
+        );
+        assert_eq!(
+            super::Receipts::<super::Receipt>::default().summary(),
+            super::ReceiptsSummary::default()
+        );
+    }
+
+    #[test]
+    fn recompute_bloom() {
+        let mut receipt = receipt(vec![log(1), log(2)]).with_bloom();
+        receipt.assert_bloom_fresh();
+
+        receipt.receipt.retain_logs(|log| log.address == Address::repeat_byte(1));
+        receipt.recompute_bloom();
+        receipt.assert_bloom_fresh();
+        assert_eq!(receipt.logs_bloom, receipt.receipt.bloom_slow());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "stale receipt bloom")]
+    fn assert_bloom_fresh_stale() {
+        let mut receipt = receipt(vec![log(0)]).with_bloom();
+        receipt.receipt.logs.clear();
+        receipt.assert_bloom_fresh();
+    }
+
+    #[test]
+    fn bloom_effective_topics() {
+        let event = |topics| log_with_topics(0, topics);
+
+        let (a, b) = (B256::repeat_byte(2), B256::repeat_byte(1));
+        let small = receipt(vec![event(vec![a, b]), event(vec![a])]);
+        assert_eq!(small.bloom_effective_topics(), vec![b, a]);
+
+        // With enough distinct topics, the bits of some topics are all set by others.
+        let topics: Vec<_> = (0..2000u64).map(|i| B256::from(U256::from(i))).collect();
+        let large = receipt(topics.iter().map(|&topic| event(vec![topic])).collect());
+        let effective = large.bloom_effective_topics();
+        assert!(effective.windows(2).all(|w| w[0] < w[1]));
+        assert!(!effective.is_empty() && effective.len() < topics.len());
+
+        let redundant = *topics.iter().find(|topic| !effective.contains(topic)).unwrap();
+        let without = large.partial_bloom(|log| log.topics() != [redundant]);
+        assert_eq!(without, large.bloom_slow());
+    }
+
+    #[test]
+    fn block_receipts_encoded_len() {
+        let log = Log {
+            address: Address::ZERO,
+            data: LogData::new_unchecked(vec![B256::ZERO], vec![0; 100].into()),
+        };
+        let with_logs = |n| receipt(vec![log.clone(); n]).with_bloom();
+        let receipts = super::Receipts {
+            receipt_vec: vec![vec![with_logs(0), with_logs(3)], vec![], vec![with_logs(1)]],
+        };
+
+        for block in 0..3 {
+            assert_eq!(
+                receipts.block_receipts_encoded_len(block),
+                receipts.block_receipts_rlp(block).map(|rlp| rlp.len())
+            );
+        }
+        assert_eq!(receipts.block_receipts_encoded_len(1), Some(1));
+        assert_eq!(receipts.block_receipts_encoded_len(3), None);
+    }
+
+    #[test]
+    fn outcome_eq() {
+        let with_status = |status, logs| super::Receipt { status, ..receipt(logs) };
+        let stored = with_status(super::Eip658Value::PostState(B256::repeat_byte(1)), vec!['a']);
+
+        assert_ne!(stored, with_status(true.into(), vec!['a']));
+        assert!(stored.outcome_eq(&with_status(true.into(), vec!['a'])));
+        assert!(!stored.outcome_eq(&with_status(false.into(), vec!['a'])));
+        assert!(!stored.outcome_eq(&with_status(true.into(), vec!['b'])));
+        assert!(stored.outcome_eq(&with_status(
+            super::Eip658Value::PostState(B256::repeat_byte(2)),
+            vec!['a']
+        )));
+    }
+
+    #[test]
+    fn split_logs() {
+        let (first, second) = receipt(vec!['a', 'b', 'c']).split_logs(1);
+        assert_eq!(first, receipt(vec!['a']));
+        assert_eq!(second, receipt(vec!['b', 'c']));
+
+        let (first, second) = receipt(vec!['a']).split_logs(5);
+        assert_eq!(first, receipt(vec!['a']));
+        assert_eq!(second, receipt(vec![]));
+    }
+
+    #[test]
+    fn logs_by_address_all() {
+        let topic_log = |address, topic| log_with_topics(address, vec![B256::repeat_byte(topic)]);
+        let receipts = super::Receipts {
+            receipt_vec: vec![
+                vec![
+                    receipt(vec![topic_log(1, 1), topic_log(2, 1)]),
+                    receipt(vec![topic_log(1, 2)]),
+                ],
+                vec![],
+                vec![receipt(vec![topic_log(1, 3)])],
+            ],
+        };
+
+        let index = receipts.logs_by_address_all();
+        assert_eq!(index.len(), 2);
+        assert_eq!(
+            index[&Address::repeat_byte(1)],
+            vec![(0, 0, &topic_log(1, 1)), (0, 1, &topic_log(1, 2)), (2, 0, &topic_log(1, 3))]
+        );
+        assert_eq!(index[&Address::repeat_byte(2)], vec![(0, 0, &topic_log(2, 1))]);
+    }
+
+    #[test]
+    fn find_empty_logs() {
+        let log_with_data = |topics, data: &'static [u8]| Log {
+            address: Address::ZERO,
+            data: LogData::new_unchecked(topics, Bytes::from_static(data)),
+        };
+        let receipt = receipt(vec![
+            log_with_data(vec![], &[]),
+            log_with_data(vec![B256::ZERO], &[]),
+            log_with_data(vec![], &[1]),
+            log_with_data(vec![], &[]),
+        ]);
+
+        assert_eq!(receipt.find_empty_logs(), vec![0, 3]);
+        assert!(super::Receipt::<Log>::default().find_empty_logs().is_empty());
+    }
+
+    #[test]
+    fn log_emitting_fraction() {
+        let with_logs = |n| receipt_with(true, 1, n);
+        let receipts = super::Receipts {
+            receipt_vec: vec![
+                vec![with_logs(2), with_logs(0)],
+                vec![],
+                vec![with_logs(1), with_logs(0)],
+            ],
+        };
+
+        assert_eq!(receipts.log_emitting_fraction(), 0.5);
+        assert_eq!(super::Receipts::<super::Receipt>::default().log_emitting_fraction(), 0.0);
+        assert_eq!(
+            super::Receipts::<super::Receipt> { receipt_vec: vec![vec![], vec![]] }
+                .log_emitting_fraction(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn root_memoized() {
+        let with_gas =
+            |cumulative_gas_used| receipt_with(true, cumulative_gas_used, 0).with_bloom();
+        let receipts = super::Receipts { receipt_vec: vec![vec![with_gas(1)], vec![]] };
+        let mut cache = HashMap::with_hasher(std::hash::RandomState::new());
+
+        let root = crate::proofs::calculate_receipt_root(&receipts[0]);
+        assert_eq!(receipts.root_memoized(0, &mut cache), Some(root));
+        assert_eq!(receipts.root_memoized(2, &mut cache), None);
+        assert_eq!(cache.len(), 1);
+
+        // Cached roots are returned without recomputation.
+        cache.insert(1, B256::ZERO);
+        assert_eq!(receipts.root_memoized(1, &mut cache), Some(B256::ZERO));
+        assert_eq!(receipts.root_memoized(0, &mut cache), Some(root));
+    }
+
+    #[test]
+    fn event_histogram() {
+        let event = |topics| log_with_topics(0, topics);
+        let (a, b) = (B256::repeat_byte(1), B256::repeat_byte(2));
+        let receipt =
+            receipt(vec![event(vec![b]), event(vec![]), event(vec![a, b]), event(vec![b, a])]);
+
+        assert_eq!(receipt.event_histogram().into_iter().collect::<Vec<_>>(), vec![(a, 1), (b, 2)]);
+        assert!(super::Receipt::<Log>::default().event_histogram().is_empty());
+    }
+
+    #[test]
+    fn new_validated() {
+        let receipt = receipt(vec![log_with_topics(1, vec![B256::ZERO])]);
+        let bloom = receipt.bloom_slow();
+
+        assert_eq!(
+            super::ReceiptWithBloom::new_validated(receipt.clone(), bloom),
+            Ok(receipt.clone().with_bloom())
+        );
+        assert_eq!(
+            super::ReceiptWithBloom::new_validated(receipt, Bloom::ZERO),
+            Err(super::ReceiptError::BloomMismatch)
+        );
+    }
+
+    #[test]
+    fn range_gas_used() {
+        let with_gas = |cumulative_gas_used| receipt_with(true, cumulative_gas_used, 0);
+        let receipts = super::Receipts {
+            receipt_vec: vec![
+                vec![with_gas(21_000), with_gas(50_000)],
+                vec![],
+                vec![with_gas(30_000)],
+            ],
+        };
+
+        assert_eq!(receipts.range_gas_used(0..3), 80_000);
+        assert_eq!(receipts.range_gas_used(1..10), 30_000);
+        assert_eq!(receipts.range_gas_used(0..1), 50_000);
+        assert_eq!(receipts.range_gas_used(5..10), 0);
+    }
+
+    #[test]
+    fn blocks_with_stale_blooms() {
+        let with_log = |byte| receipt(vec![log(byte)]).with_bloom();
+        let mut stale = with_log(1);
+        stale.logs_bloom = Bloom::ZERO;
+        assert!(!stale.verify_bloom());
+
+        let receipts = super::Receipts {
+            receipt_vec: vec![
+                vec![with_log(1), with_log(2)],
+                vec![with_log(1), stale.clone()],
+                vec![],
+                vec![stale],
+            ],
+        };
+        assert_eq!(receipts.blocks_with_stale_blooms(), vec![1, 3]);
+    }
+
+    #[test]
+    fn partition_by_status() {
+        let with_status = |status| receipt_with(status, 1, 0);
+        let receipts = super::Receipts {
+            receipt_vec: vec![
+                vec![with_status(true), with_status(false)],
+                vec![],
+                vec![with_status(true)],
+            ],
+        };
+
+        let (succeeded, failed) = receipts.partition_by_status();
+        assert_eq!(succeeded, vec![(0, 0, &receipts[0][0]), (2, 0, &receipts[2][0])]);
+        assert_eq!(failed, vec![(0, 1, &receipts[0][1])]);
+    }
+
+    #[test]
+    fn gas_used_minmax() {
+        let with_gas = |cumulative_gas_used| receipt_with(true, cumulative_gas_used, 0);
+        let receipts = super::Receipts {
+            receipt_vec: vec![
+                vec![with_gas(21_000), with_gas(71_000), with_gas(92_000)],
+                vec![],
+                vec![with_gas(30_000)],
+            ],
+        };
+
+        assert_eq!(receipts.gas_used_minmax(0), Some((21_000, 50_000)));
+        assert_eq!(receipts.gas_used_minmax(1), None);
+        assert_eq!(receipts.gas_used_minmax(2), Some((30_000, 30_000)));
+        assert_eq!(receipts.gas_used_minmax(3), None);
+    }
+
+    #[test]
+    fn clone_logs_with() {
+        let receipt =
+            super::Receipt { status: false.into(), cumulative_gas_used: 7, logs: vec![1, 2] };
+        let mut calls = 0;
+        let cloned = receipt.clone_logs_with(|log| {
+            calls += 1;
+            log * 10
+        });
+
+        assert_eq!(calls, 2);
+        assert_eq!(
+            cloned,
+            super::Receipt { status: false.into(), cumulative_gas_used: 7, logs: vec![10, 20] }
+        );
+    }
+
+    #[test]
+    fn block_summaries() {
+        let receipts = super::Receipts {
+            receipt_vec: vec![
+                vec![receipt_with(true, 21_000, 2), receipt_with(false, 1_200_000, 0)],
+                vec![],
+                vec![receipt_with(true, 999, 1)],
+                vec![receipt_with(true, 2_500_000_000, 0)],
+            ],
+        };
+
+        assert_eq!(
+            receipts.block_summaries(),
+            vec![
+                "block 0: 2 txs, 1 failed, 2 logs, gas 1.2M",
+                "block 1: 0 txs, 0 failed, 0 logs, gas 0",
+                "block 2: 1 txs, 0 failed, 1 logs, gas 999",
+                "block 3: 1 txs, 0 failed, 0 logs, gas 2.5G",
+            ]
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn logs_par() {
+        use rayon::iter::ParallelIterator;
+
+        let receipt = receipt((0..100).map(log).collect());
+        assert_eq!(receipt.logs_par().collect::<Vec<_>>(), receipt.logs.iter().collect::<Vec<_>>());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn summary_par() {
+        let receipts = super::Receipts {
+            receipt_vec: (0..100)
+                .map(|block| (0..block % 7).map(|i| receipt_with(i % 3 != 0, 1_000, i)).collect())
+                .collect(),
+        };
+        assert_eq!(receipts.summary_par(), receipts.summary());
+        assert_eq!(
+            super::Receipts::<super::Receipt>::default().summary_par(),
+            super::ReceiptsSummary::default()
+        );
+    }
 }
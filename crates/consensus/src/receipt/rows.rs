@@ -0,0 +1,261 @@
+//! Conversion from rows of a receipt table, as stored in SQL or key-value databases.
+
+use crate::{Receipt, Receipts, MAX_EMPTY_BLOCK_FILL};
+use alloc::vec::Vec;
+use alloy_primitives::{Bytes, Log};
+use alloy_rlp::Decodable;
+use core::fmt;
+
+/// A single receipt as stored in a database table.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReceiptRow {
+    /// The number of the block containing the transaction.
+    pub block_number: u64,
+    /// The index of the transaction within its block.
+    pub tx_index: u64,
+    /// The status of the transaction, `1` for success and `0` for failure.
+    pub status: u8,
+    /// The cumulative gas used.
+    pub cumulative_gas_used: u128,
+    /// The RLP encoded list of logs.
+    pub logs: Bytes,
+}
+
+impl Receipts<Receipt<Log>> {
+    /// Assembles a [`Receipts`] collection from the rows of a receipt table.
+    ///
+    /// Rows must be ordered by block number and transaction index, as the transaction indices of
+    /// each block must be contiguous and start at zero. Block numbers may have gaps, e.g. for
+    /// blocks without transactions, which are filled with up to [`MAX_EMPTY_BLOCK_FILL`] empty
+    /// blocks in total. The first block of the collection is the block of the first row.
+    ///
+    /// Errors of the row iterator are returned as is, invalid rows are reported as
+    /// [`ReceiptRowError`].
+    pub fn from_rows<I, E>(rows: I) -> Result<Self, E>
+    where
+        I: IntoIterator<Item = Result<ReceiptRow, E>>,
+        E: From<ReceiptRowError>,
+    {
+        let mut receipt_vec: Vec<Vec<Receipt<Log>>> = Vec::new();
+        let mut first_block = None;
+        let mut empty_blocks = 0usize;
+        for row in rows {
+            let row = row?;
+            let first = *first_block.get_or_insert(row.block_number);
+            let block = row
+                .block_number
+                .checked_sub(first)
+                .filter(|&block| block.saturating_add(1) >= receipt_vec.len() as u64)
+                .ok_or(ReceiptRowError::UnorderedBlock { block_number: row.block_number })?;
+            if block >= receipt_vec.len() as u64 {
+                // Block numbers are untrusted, so bound the empty blocks filled in for gaps.
+                let gap = block - receipt_vec.len() as u64;
+                empty_blocks = usize::try_from(gap)
+                    .ok()
+                    .and_then(|gap| empty_blocks.checked_add(gap))
+                    .filter(|&total| total <= MAX_EMPTY_BLOCK_FILL)
+                    .ok_or(ReceiptRowError::BlockGapTooLarge { block_number: row.block_number })?;
+                receipt_vec.resize_with(receipt_vec.len() + gap as usize + 1, Vec::new);
+            }
+
+            let block = receipt_vec.len() - 1;
+            let receipts = &mut receipt_vec[block];
+            if row.tx_index != receipts.len() as u64 {
+                return Err(ReceiptRowError::NonContiguousTxIndex {
+                    block_number: row.block_number,
+                    expected: receipts.len() as u64,
+                    got: row.tx_index,
+                }
+                .into());
+            }
+
+            let status = match row.status {
+                0 => false,
+                1 => true,
+                status => {
+                    return Err(ReceiptRowError::InvalidStatus {
+                        block_number: row.block_number,
+                        tx_index: row.tx_index,
+                        status,
+                    }
+                    .into())
+                }
+            };
+            let buf = &mut row.logs.as_ref();
+            let logs = Vec::<Log>::decode(buf)
+                .and_then(|logs| {
+                    // Trailing bytes indicate a corrupted or concatenated value.
+                    if buf.is_empty() {
+                        Ok(logs)
+                    } else {
+                        Err(alloy_rlp::Error::UnexpectedLength)
+                    }
+                })
+                .map_err(|err| ReceiptRowError::Logs {
+                    block_number: row.block_number,
+                    tx_index: row.tx_index,
+                    err,
+                })?;
+
+            receipts.push(Receipt {
+                status: status.into(),
+                cumulative_gas_used: row.cumulative_gas_used,
+                logs,
+            });
+        }
+        Ok(Self { receipt_vec })
+    }
+}
+
+/// Error returned by [`Receipts::from_rows`] for invalid rows.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReceiptRowError {
+    /// A row belongs to a block preceding the block of an earlier row.
+    UnorderedBlock {
+        /// The block number of the row.
+        block_number: u64,
+    },
+    /// A row would require filling in more than [`MAX_EMPTY_BLOCK_FILL`] empty blocks in total.
+    BlockGapTooLarge {
+        /// The block number of the row.
+        block_number: u64,
+    },
+    /// The transaction index of a row does not follow the previous row of its block.
+    NonContiguousTxIndex {
+        /// The block number of the row.
+        block_number: u64,
+        /// The expected transaction index.
+        expected: u64,
+        /// The transaction index of the row.
+        got: u64,
+    },
+    /// The status of a row is neither `0` nor `1`.
+    InvalidStatus {
+        /// The block number of the row.
+        block_number: u64,
+        /// The transaction index of the row.
+        tx_index: u64,
+        /// The status of the row.
+        status: u8,
+    },
+    /// The logs of a row could not be decoded.
+    Logs {
+        /// The block number of the row.
+        block_number: u64,
+        /// The transaction index of the row.
+        tx_index: u64,
+        /// The decoding error.
+        err: alloy_rlp::Error,
+    },
+}
+
+impl fmt::Display for ReceiptRowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnorderedBlock { block_number } => {
+                write!(f, "receipt row of block {block_number} is out of order")
+            }
+            Self::BlockGapTooLarge { block_number } => {
+                write!(f, "too many empty blocks before receipt row of block {block_number}")
+            }
+            Self::NonContiguousTxIndex { block_number, expected, got } => {
+                write!(f, "expected tx index {expected} in block {block_number}, got {got}")
+            }
+            Self::InvalidStatus { block_number, tx_index, status } => {
+                write!(f, "invalid status {status} for tx {tx_index} in block {block_number}")
+            }
+            Self::Logs { block_number, tx_index, err } => {
+                write!(f, "invalid logs for tx {tx_index} in block {block_number}: {err}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ReceiptRowError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::Address;
+
+    fn row(
+        block_number: u64,
+        tx_index: u64,
+        logs: Vec<Log>,
+    ) -> Result<ReceiptRow, ReceiptRowError> {
+        Ok(ReceiptRow {
+            block_number,
+            tx_index,
+            status: 1,
+            cumulative_gas_used: 21_000 * (tx_index as u128 + 1),
+            logs: alloy_rlp::encode(logs).into(),
+        })
+    }
+
+    #[test]
+    fn from_rows() {
+        let log = Log::new_unchecked(Address::repeat_byte(1), vec![], Default::default());
+        let receipts = Receipts::from_rows(vec![
+            row(5, 0, vec![]),
+            row(5, 1, vec![log.clone()]),
+            row(7, 0, vec![]),
+        ])
+        .unwrap();
+
+        assert_eq!(receipts.receipt_vec.iter().map(Vec::len).collect::<Vec<_>>(), vec![2, 0, 1]);
+        assert_eq!(receipts.receipt_vec[0][1].logs, vec![log]);
+        assert_eq!(receipts.receipt_vec[0][1].cumulative_gas_used, 42_000);
+        assert_eq!(
+            Receipts::from_rows(Vec::<Result<_, ReceiptRowError>>::new()),
+            Ok(Receipts::default())
+        );
+    }
+
+    #[test]
+    fn from_invalid_rows() {
+        assert_eq!(
+            Receipts::from_rows(vec![row(5, 0, vec![]), row(5, 2, vec![])]),
+            Err(ReceiptRowError::NonContiguousTxIndex { block_number: 5, expected: 1, got: 2 })
+        );
+        assert_eq!(
+            Receipts::from_rows(vec![row(5, 0, vec![]), row(6, 0, vec![]), row(5, 1, vec![])]),
+            Err(ReceiptRowError::UnorderedBlock { block_number: 5 })
+        );
+
+        let max = MAX_EMPTY_BLOCK_FILL as u64;
+        let rows = vec![row(0, 0, vec![]), row(max / 2, 0, vec![]), row(max + 3, 0, vec![])];
+        assert_eq!(
+            Receipts::from_rows(rows),
+            Err(ReceiptRowError::BlockGapTooLarge { block_number: max + 3 })
+        );
+        assert_eq!(
+            Receipts::from_rows(vec![row(0, 0, vec![]), row(u64::MAX, 0, vec![])]),
+            Err(ReceiptRowError::BlockGapTooLarge { block_number: u64::MAX })
+        );
+
+        let mut invalid = row(1, 0, vec![]).unwrap();
+        invalid.status = 2;
+        assert_eq!(
+            Receipts::from_rows(vec![Ok(invalid.clone())]),
+            Err(ReceiptRowError::InvalidStatus { block_number: 1, tx_index: 0, status: 2 })
+        );
+
+        invalid.status = 0;
+        invalid.logs = Bytes::from_static(&[0x80]);
+        assert!(matches!(
+            Receipts::from_rows(vec![Ok(invalid)]),
+            Err(ReceiptRowError::Logs { block_number: 1, tx_index: 0, .. })
+        ));
+
+        let mut trailing = row(1, 0, vec![]).unwrap();
+        trailing.logs = Bytes::from_static(&[alloy_rlp::EMPTY_LIST_CODE, 0x00]);
+        assert_eq!(
+            Receipts::from_rows(vec![Ok(trailing)]),
+            Err(ReceiptRowError::Logs {
+                block_number: 1,
+                tx_index: 0,
+                err: alloy_rlp::Error::UnexpectedLength
+            })
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use alloy_primitives::Log;
+use core::borrow::Borrow;
+
+#[cfg(feature = "serde")]
+pub use rpc_receipt::deserialize_rpc_receipt;
+
+/// A log as returned over RPC, carrying the `removed` flag that consensus [`Log`]s lack.
+///
+/// The flag is `true` if the log was removed due to a chain reorganization and defaults to
+/// `false` when absent.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RpcLog<T = Log> {
+    /// The consensus log.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub inner: T,
+    /// Whether the log was removed due to a chain reorganization.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub removed: bool,
+}
+
+impl<T> RpcLog<T> {
+    /// Creates a new [`RpcLog`] with the given `removed` flag.
+    pub const fn new(inner: T, removed: bool) -> Self {
+        Self { inner, removed }
+    }
+
+    /// Consumes the wrapper, returning the consensus log.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T> From<T> for RpcLog<T> {
+    fn from(inner: T) -> Self {
+        Self { inner, removed: false }
+    }
+}
+
+impl<T: Borrow<Log>> Borrow<Log> for RpcLog<T> {
+    fn borrow(&self) -> &Log {
+        self.inner.borrow()
+    }
+}
+
+#[cfg(feature = "serde")]
+mod rpc_receipt {
+    use crate::{Eip658Value, Receipt, ReceiptWithBloom};
+    use alloc::vec::Vec;
+    use alloy_primitives::{Bloom, Log};
+    use serde::{Deserialize, Deserializer};
+
+    /// The consensus fields of an RPC receipt object, RPC-only fields are ignored.
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct RpcReceipt {
+        #[serde(flatten)]
+        status: Eip658Value,
+        #[serde(with = "alloy_serde::quantity")]
+        cumulative_gas_used: u128,
+        logs: Vec<Log>,
+        logs_bloom: Bloom,
+    }
+
+    /// Deserializes an RPC receipt object, e.g. an element of an `eth_getBlockReceipts`
+    /// response, into a [`ReceiptWithBloom`].
+    ///
+    /// Reads `status` or `root`, `cumulativeGasUsed`, `logs` and `logsBloom`, and ignores all
+    /// RPC-only fields such as `transactionHash`, `gasUsed` or the per-log `removed` flag.
+    ///
+    /// Can be used with `#[serde(deserialize_with = "...")]`.
+    pub fn deserialize_rpc_receipt<'de, D>(
+        deserializer: D,
+    ) -> Result<ReceiptWithBloom<Receipt<Log>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let RpcReceipt { status, cumulative_gas_used, logs, logs_bloom } =
+            RpcReceipt::deserialize(deserializer)?;
+        Ok(ReceiptWithBloom::new(Receipt { status, cumulative_gas_used, logs }, logs_bloom))
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::Receipt;
+    use alloy_primitives::{address, b256, bytes, LogData};
+
+    #[test]
+    fn serde_rpc_log() {
+        let receipt = Receipt {
+            status: true.into(),
+            cumulative_gas_used: 0x5208,
+            logs: vec![Log {
+                address: address!("0000000000000000000000000000000000000011"),
+                data: LogData::new_unchecked(
+                    vec![b256!("000000000000000000000000000000000000000000000000000000000000dead")],
+                    bytes!("0100ff"),
+                ),
+            }],
+        }
+        .into_rpc_logs();
+
+        let json = serde_json::to_string(&receipt).unwrap();
+        assert_eq!(
+            json,
+            r#"{"status":"0x1","cumulativeGasUsed":"0x5208","logs":[{"address":"0x0000000000000000000000000000000000000011","topics":["0x000000000000000000000000000000000000000000000000000000000000dead"],"data":"0x0100ff","removed":false}]}"#
+        );
+        assert_eq!(serde_json::from_str::<Receipt<RpcLog>>(&json).unwrap(), receipt);
+
+        let log: RpcLog = serde_json::from_str(
+            r#"{"address":"0x0000000000000000000000000000000000000011","topics":[],"data":"0x"}"#,
+        )
+        .unwrap();
+        assert!(!log.removed);
+    }
+
+    #[test]
+    fn deserialize_block_receipts_element() {
+        let json = r#"{"transactionHash":"0x21f6554c28453a01e7276c1db2fc1695bb512b170818bfa98fa8136433100616","blockHash":"0x4acbdefb861ef4adedb135ca52865f6743451bfbfa35db78076f881a40401a5e","blockNumber":"0x129f4b9","logsBloom":"0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000200000000000000000040000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000400000800000000000000000000000000000000004000000000000000000800000000100000020000000000000000000080000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000010000000000000000000000000000","gasUsed":"0xbde1","contractAddress":null,"cumulativeGasUsed":"0xa42aec","transactionIndex":"0x7f","from":"0x9a53bfba35269414f3b2d20b52ca01b15932c7b2","to":"0xdac17f958d2ee523a2206206994597c13d831ec7","type":"0x2","effectiveGasPrice":"0xfb0f6e8c9","logs":[{"blockHash":"0x4acbdefb861ef4adedb135ca52865f6743451bfbfa35db78076f881a40401a5e","address":"0xdac17f958d2ee523a2206206994597c13d831ec7","logIndex":"0x118","data":"0x00000000000000000000000000000000000000000052b7d2dcc80cd2e4000000","removed":false,"topics":["0x8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925","0x0000000000000000000000009a53bfba35269414f3b2d20b52ca01b15932c7b2","0x00000000000000000000000039e5dbb9d2fead31234d7c647d6ce77d85826f76"],"blockNumber":"0x129f4b9","transactionIndex":"0x7f","transactionHash":"0x21f6554c28453a01e7276c1db2fc1695bb512b170818bfa98fa8136433100616"}],"status":"0x1"}"#;
+
+        let receipt =
+            deserialize_rpc_receipt(&mut serde_json::Deserializer::from_str(json)).unwrap();
+        assert!(receipt.receipt.status.coerce_status());
+        assert_eq!(receipt.receipt.cumulative_gas_used, 0xa42aec);
+        assert_eq!(receipt.receipt.logs.len(), 1);
+        assert_eq!(
+            receipt.receipt.logs[0].address,
+            address!("dac17f958d2ee523a2206206994597c13d831ec7")
+        );
+        assert_eq!(receipt.logs_bloom, receipt.receipt.bloom_slow());
+    }
+}
@@ -0,0 +1,103 @@
+use crate::receipt::{receipts::aggregate_bloom, LogFilter, Receipts, TxReceipt};
+use alloc::{sync::Arc, vec::Vec};
+use alloy_primitives::{Bloom, Log};
+use core::borrow::Borrow;
+
+/// A shareable, immutable [`Receipts`] collection with precomputed per-block bloom filters.
+///
+/// Cloning is cheap, so the collection can be shared across threads that each run
+/// bloom-prefiltered log queries without locking or recomputing blooms. The blooms are computed
+/// once in [`ReceiptsArc::new`], which costs one pass over every log of the collection.
+#[derive(Debug)]
+pub struct ReceiptsArc<T> {
+    receipts: Arc<Receipts<T>>,
+    blooms: Arc<[Bloom]>,
+}
+
+impl<T> Clone for ReceiptsArc<T> {
+    fn clone(&self) -> Self {
+        Self { receipts: self.receipts.clone(), blooms: self.blooms.clone() }
+    }
+}
+
+impl<T: TxReceipt> ReceiptsArc<T> {
+    /// Wraps the receipts and computes the bloom filter of each block.
+    pub fn new(receipts: Receipts<T>) -> Self {
+        let blooms = receipts.receipt_vec.iter().map(|block| aggregate_bloom(block)).collect();
+        Self { receipts: Arc::new(receipts), blooms }
+    }
+}
+
+impl<T> ReceiptsArc<T> {
+    /// Returns the shared receipts.
+    pub const fn receipts(&self) -> &Arc<Receipts<T>> {
+        &self.receipts
+    }
+
+    /// Returns the precomputed bloom filters, one per block.
+    pub fn blooms(&self) -> &[Bloom] {
+        &self.blooms
+    }
+}
+
+impl<T> ReceiptsArc<T>
+where
+    T: TxReceipt,
+    T::Log: Borrow<Log>,
+{
+    /// Returns all logs matching the filter, along with the index of their block and of their
+    /// receipt within the block.
+    ///
+    /// Blocks whose bloom cannot contain a match are skipped without inspecting their logs.
+    pub fn query(&self, filter: &LogFilter) -> Vec<(usize, usize, Log)> {
+        let mut matches = Vec::new();
+        for (block, receipts) in self.receipts.receipt_vec.iter().enumerate() {
+            if !filter.matches_bloom(&self.blooms[block]) {
+                continue;
+            }
+            for (tx, receipt) in receipts.iter().enumerate() {
+                matches.extend(
+                    receipt
+                        .logs()
+                        .iter()
+                        .map(Borrow::borrow)
+                        .filter(|log| filter.matches(log))
+                        .map(|log| (block, tx, log.clone())),
+                );
+            }
+        }
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::receipt::test_utils::{log_with_topics, receipt};
+    use alloy_primitives::{Address, B256};
+
+    #[test]
+    fn query_shared_receipts() {
+        let log = |address, topic| log_with_topics(address, vec![B256::repeat_byte(topic)]);
+        let receipts = Receipts {
+            receipt_vec: vec![
+                vec![receipt(vec![log(1, 1)]), receipt(vec![log(2, 1), log(1, 2)])],
+                vec![],
+                vec![receipt(vec![log(1, 2)])],
+            ],
+        };
+        let shared = ReceiptsArc::new(receipts);
+        assert_eq!(shared.blooms().len(), 3);
+
+        let filter = LogFilter::new().address(Address::repeat_byte(1));
+        let handle = shared.clone();
+        let found = std::thread::spawn(move || handle.query(&filter)).join().unwrap();
+        assert_eq!(found, vec![(0, 0, log(1, 1)), (0, 1, log(1, 2)), (2, 0, log(1, 2))]);
+
+        let filter = LogFilter::new().address(Address::repeat_byte(1)).event_signature(B256::ZERO);
+        assert!(shared.query(&filter).is_empty());
+
+        let filter = LogFilter::new().event_signature(B256::repeat_byte(1));
+        assert_eq!(shared.query(&filter), vec![(0, 0, log(1, 1)), (0, 1, log(2, 1))]);
+    }
+}
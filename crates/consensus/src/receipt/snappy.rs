@@ -0,0 +1,204 @@
+//! Snappy compressed receipt lists, as stored in geth's freezer.
+
+use crate::receipt::{Receipt, ReceiptWithBloom};
+use alloc::vec::Vec;
+use alloy_primitives::Log;
+use alloy_rlp::{Decodable, Encodable};
+use core::{borrow::Borrow, fmt};
+
+/// The maximum decompressed length accepted by [`decode_snappy_receipts`], 64 MiB.
+///
+/// The decompressed length is read from the header of the snappy block, which is untrusted, so it
+/// is capped before allocating. This is well above the size of the receipts of any block.
+pub const MAX_SNAPPY_RECEIPTS_LEN: usize = 64 << 20;
+
+/// An error that can occur when encoding or decoding snappy compressed receipts.
+#[derive(Debug)]
+pub enum SnappyReceiptsError {
+    /// The input could not be compressed or decompressed.
+    Snappy(snap::Error),
+    /// The decompressed input is not a valid RLP list of receipts.
+    Rlp(alloy_rlp::Error),
+    /// The decompressed length exceeds [`MAX_SNAPPY_RECEIPTS_LEN`].
+    TooLarge(usize),
+}
+
+impl From<snap::Error> for SnappyReceiptsError {
+    fn from(err: snap::Error) -> Self {
+        Self::Snappy(err)
+    }
+}
+
+impl From<alloy_rlp::Error> for SnappyReceiptsError {
+    fn from(err: alloy_rlp::Error) -> Self {
+        Self::Rlp(err)
+    }
+}
+
+impl fmt::Display for SnappyReceiptsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Snappy(err) => write!(f, "snappy compression error: {err}"),
+            Self::Rlp(err) => write!(f, "receipts RLP decoding error: {err}"),
+            Self::TooLarge(len) => write!(
+                f,
+                "decompressed receipts length {len} exceeds maximum of {MAX_SNAPPY_RECEIPTS_LEN}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SnappyReceiptsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Snappy(err) => Some(err),
+            Self::Rlp(err) => Some(err),
+            Self::TooLarge(_) => None,
+        }
+    }
+}
+
+/// Decompresses a snappy compressed blob and decodes it as an RLP list of receipts in geth's
+/// storage encoding, recomputing the bloom filter of each receipt.
+///
+/// This is the format of the receipts of a block in geth's freezer (ancient store): a snappy
+/// block of the RLP list of receipts, each encoded as in [`Receipt::rlp_encode_without_bloom`],
+/// i.e. `[status or post state, cumulative gas used, logs]`. The legacy storage encodings of geth
+/// releases before v1.9, which also include the transaction hash, contract address and gas used,
+/// are not supported.
+///
+/// Fails without decompressing if the decompressed length exceeds [`MAX_SNAPPY_RECEIPTS_LEN`].
+pub fn decode_snappy_receipts<T>(
+    compressed: &[u8],
+) -> Result<Vec<ReceiptWithBloom<Receipt<T>>>, SnappyReceiptsError>
+where
+    T: Decodable + Borrow<Log>,
+{
+    let len = snap::raw::decompress_len(compressed)?;
+    if len > MAX_SNAPPY_RECEIPTS_LEN {
+        return Err(SnappyReceiptsError::TooLarge(len));
+    }
+    let decompressed = snap::raw::Decoder::new().decompress_vec(compressed)?;
+    let mut buf = decompressed.as_slice();
+    let header = alloy_rlp::Header::decode(&mut buf)?;
+    if !header.list {
+        return Err(alloy_rlp::Error::UnexpectedString.into());
+    }
+    if header.payload_length != buf.len() {
+        return Err(alloy_rlp::Error::UnexpectedLength.into());
+    }
+
+    let mut receipts = Vec::new();
+    while !buf.is_empty() {
+        receipts.push(Receipt::rlp_decode_without_bloom(&mut buf)?.with_bloom());
+    }
+    Ok(receipts)
+}
+
+/// RLP encodes the receipts as a list in geth's storage encoding and compresses the result with
+/// snappy.
+///
+/// This is the inverse of [`decode_snappy_receipts`]. Fails if the encoded receipts exceed the
+/// maximum size of a snappy block.
+pub fn encode_snappy_receipts<T: Encodable>(
+    receipts: &[Receipt<T>],
+) -> Result<Vec<u8>, SnappyReceiptsError> {
+    let payload_length = receipts.iter().map(Receipt::rlp_encoded_length_without_bloom).sum();
+    let header = alloy_rlp::Header { list: true, payload_length };
+
+    let mut encoded = Vec::with_capacity(header.length_with_payload());
+    header.encode(&mut encoded);
+    for receipt in receipts {
+        receipt.rlp_encode_without_bloom(&mut encoded);
+    }
+    Ok(snap::raw::Encoder::new().compress_vec(&encoded)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{b256, bytes, hex, Address, Bloom, LogData, B256};
+
+    #[test]
+    fn snappy_roundtrip() {
+        let receipts = vec![
+            Receipt {
+                status: true.into(),
+                cumulative_gas_used: 21000,
+                logs: vec![Log {
+                    address: Address::repeat_byte(1),
+                    data: LogData::new_unchecked(vec![], vec![0; 64].into()),
+                }],
+            },
+            Receipt { status: false.into(), cumulative_gas_used: 42000, logs: vec![] },
+        ];
+
+        let compressed = encode_snappy_receipts(&receipts).unwrap();
+        let decoded = decode_snappy_receipts::<Log>(&compressed).unwrap();
+        assert_eq!(decoded, receipts.into_iter().map(Receipt::with_bloom).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn snappy_geth_storage_receipts() {
+        // Freezer items in geth's `ReceiptForStorage` encoding, stored as snappy literals: a block
+        // with a successful receipt and a failed receipt with a log, and a pre-Byzantium block with
+        // a post state root.
+        let byzantium = hex!("4df04cf84bc501825208c0f8438082a410f83df83b941111111111111111111111111111111111111111e1a0dddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddd830100ff");
+        let frontier = hex!(
+            "2798e6e5a0284d35bf53b82ef480ab4208527325477439c64fb90ef518450f05ee151c8e1082f618c0"
+        );
+
+        let log = Log {
+            address: Address::repeat_byte(0x11),
+            data: LogData::new_unchecked(vec![B256::repeat_byte(0xdd)], bytes!("0100ff")),
+        };
+        let receipts = vec![
+            Receipt { status: true.into(), cumulative_gas_used: 0x5208, logs: vec![] },
+            Receipt { status: false.into(), cumulative_gas_used: 0xa410, logs: vec![log] },
+        ];
+        let decoded = decode_snappy_receipts::<Log>(&byzantium).unwrap();
+        assert_eq!(decoded, receipts.iter().cloned().map(Receipt::with_bloom).collect::<Vec<_>>());
+        assert_ne!(decoded[1].logs_bloom, Bloom::ZERO);
+
+        let decoded = decode_snappy_receipts::<Log>(&frontier).unwrap();
+        assert_eq!(
+            decoded,
+            vec![Receipt {
+                status: b256!("284d35bf53b82ef480ab4208527325477439c64fb90ef518450f05ee151c8e10")
+                    .into(),
+                cumulative_gas_used: 0xf618,
+                logs: vec![],
+            }
+            .with_bloom()]
+        );
+
+        // Re-encoding produces the same RLP.
+        let compressed = encode_snappy_receipts(&receipts).unwrap();
+        let decoder = &mut snap::raw::Decoder::new();
+        assert_eq!(
+            decoder.decompress_vec(&compressed).unwrap(),
+            decoder.decompress_vec(&byzantium).unwrap()
+        );
+    }
+
+    #[test]
+    fn snappy_errors() {
+        let err = decode_snappy_receipts::<Log>(&[0xff, 0xff, 0xff]).unwrap_err();
+        assert!(matches!(err, SnappyReceiptsError::Snappy(_)));
+
+        // A header claiming a decompressed length of 4 GiB is rejected before allocating.
+        let err = decode_snappy_receipts::<Log>(&[0xff, 0xff, 0xff, 0xff, 0x0f]).unwrap_err();
+        assert!(matches!(err, SnappyReceiptsError::TooLarge(0xffff_ffff)));
+
+        let compressed = snap::raw::Encoder::new().compress_vec(&[0x01]).unwrap();
+        let err = decode_snappy_receipts::<Log>(&compressed).unwrap_err();
+        assert!(matches!(err, SnappyReceiptsError::Rlp(_)));
+
+        // Receipts in the consensus encoding, i.e. with a bloom filter, are rejected.
+        let mut encoded = Vec::new();
+        alloy_rlp::encode_list(&[Receipt::<Log>::default().with_bloom()], &mut encoded);
+        let compressed = snap::raw::Encoder::new().compress_vec(&encoded).unwrap();
+        let err = decode_snappy_receipts::<Log>(&compressed).unwrap_err();
+        assert!(matches!(err, SnappyReceiptsError::Rlp(_)));
+    }
+}
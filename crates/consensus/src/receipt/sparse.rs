@@ -0,0 +1,132 @@
+use crate::receipt::{Receipts, MAX_EMPTY_BLOCK_FILL};
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::fmt;
+
+/// A compact representation of [`Receipts`] that only stores the non-empty blocks, keyed by their
+/// index.
+///
+/// This avoids emitting an empty entry for every block without receipts when serializing a sparse
+/// range, e.g. `{ "blocks": { "3": [...], "7": [...] }, "len": 10 }`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SparseReceipts<T> {
+    /// The non-empty blocks, keyed by their index.
+    pub blocks: BTreeMap<usize, Vec<T>>,
+    /// The total number of blocks, including empty ones.
+    pub len: usize,
+}
+
+impl<T> Default for SparseReceipts<T> {
+    fn default() -> Self {
+        Self { blocks: BTreeMap::new(), len: 0 }
+    }
+}
+
+impl<T> From<Receipts<T>> for SparseReceipts<T> {
+    fn from(receipts: Receipts<T>) -> Self {
+        let len = receipts.receipt_vec.len();
+        let blocks = receipts
+            .receipt_vec
+            .into_iter()
+            .enumerate()
+            .filter(|(_, block)| !block.is_empty())
+            .collect();
+        Self { blocks, len }
+    }
+}
+
+impl<T> TryFrom<SparseReceipts<T>> for Receipts<T> {
+    type Error = SparseReceiptsError;
+
+    fn try_from(sparse: SparseReceipts<T>) -> Result<Self, Self::Error> {
+        let len = sparse.len;
+        if let Some(&index) = sparse.blocks.keys().next_back().filter(|&&index| index >= len) {
+            return Err(SparseReceiptsError::IndexOutOfBounds { index, len });
+        }
+        // The length is untrusted, so bound the number of empty blocks before allocating.
+        if len - sparse.blocks.len() > MAX_EMPTY_BLOCK_FILL {
+            return Err(SparseReceiptsError::TooManyEmptyBlocks { len });
+        }
+
+        let mut receipt_vec = Vec::with_capacity(len);
+        for (index, block) in sparse.blocks {
+            receipt_vec.resize_with(index, Vec::new);
+            receipt_vec.push(block);
+        }
+        receipt_vec.resize_with(len, Vec::new);
+        Ok(Self { receipt_vec })
+    }
+}
+
+/// The error returned when converting invalid [`SparseReceipts`] into [`Receipts`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SparseReceiptsError {
+    /// A block index is out of bounds.
+    IndexOutOfBounds {
+        /// The offending block index.
+        index: usize,
+        /// The total number of blocks.
+        len: usize,
+    },
+    /// More than [`MAX_EMPTY_BLOCK_FILL`] blocks are empty.
+    TooManyEmptyBlocks {
+        /// The total number of blocks.
+        len: usize,
+    },
+}
+
+impl fmt::Display for SparseReceiptsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IndexOutOfBounds { index, len } => {
+                write!(f, "block index {index} out of bounds for {len} blocks")
+            }
+            Self::TooManyEmptyBlocks { len } => {
+                write!(f, "too many empty blocks for {len} blocks")
+            }
+        }
+    }
+}
+
+impl core::error::Error for SparseReceiptsError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sparse_roundtrip() {
+        let receipts = Receipts { receipt_vec: vec![vec![], vec![1, 2], vec![], vec![3], vec![]] };
+
+        let sparse = SparseReceipts::from(receipts.clone());
+        assert_eq!(sparse.blocks.keys().copied().collect::<Vec<_>>(), vec![1, 3]);
+        assert_eq!(sparse.len, 5);
+        assert_eq!(Receipts::try_from(sparse), Ok(receipts));
+
+        let sparse = SparseReceipts { blocks: BTreeMap::from([(5, vec![1])]), len: 5 };
+        assert_eq!(
+            Receipts::try_from(sparse),
+            Err(SparseReceiptsError::IndexOutOfBounds { index: 5, len: 5 })
+        );
+
+        let sparse = SparseReceipts { blocks: BTreeMap::from([(0, vec![1])]), len: usize::MAX };
+        assert_eq!(
+            Receipts::try_from(sparse),
+            Err(SparseReceiptsError::TooManyEmptyBlocks { len: usize::MAX })
+        );
+        let len = MAX_EMPTY_BLOCK_FILL + 1;
+        let sparse = SparseReceipts { blocks: BTreeMap::from([(0, vec![1])]), len };
+        assert_eq!(Receipts::try_from(sparse).map(|receipts| receipts.len()), Ok(len));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn sparse_serde() {
+        let receipts = Receipts { receipt_vec: vec![vec![], vec![], vec![], vec![1u8], vec![]] };
+        let json = serde_json::to_string(&SparseReceipts::from(receipts.clone())).unwrap();
+        assert_eq!(json, r#"{"blocks":{"3":[1]},"len":5}"#);
+
+        let sparse: SparseReceipts<u8> = serde_json::from_str(&json).unwrap();
+        assert_eq!(Receipts::try_from(sparse), Ok(receipts));
+    }
+}
@@ -2,6 +2,8 @@ use alloy_primitives::B256;
 use alloy_rlp::{Buf, BufMut, Decodable, Encodable, Error, Header};
 
 /// Captures the result of a transaction execution.
+///
+/// Defaults to a successful status, i.e. `Eip658Value::Eip658(true)`.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
 pub enum Eip658Value {
@@ -67,7 +69,6 @@ impl From<B256> for Eip658Value {
     }
 }
 
-// NB: default to success
 impl Default for Eip658Value {
     fn default() -> Self {
         Self::Eip658(true)
@@ -147,6 +148,11 @@ impl Encodable for Eip658Value {
     }
 }
 
+/// Decodes the status from its RLP encoding.
+///
+/// The status is expected to be a single byte or empty. With the `legacy-status` feature, which is
+/// off by default, non-standard multi-byte statuses of up to 4 bytes as found in some very old or
+/// non-standard chain data are accepted as well, where any non-zero value is coerced to success.
 impl Decodable for Eip658Value {
     fn decode(buf: &mut &[u8]) -> Result<Self, Error> {
         let h = Header::decode(buf)?;
@@ -165,6 +171,15 @@ impl Decodable for Eip658Value {
                 buf.copy_to_slice(state.as_mut_slice());
                 Ok(state.into())
             }
+            #[cfg(feature = "legacy-status")]
+            len @ 2..=4 => {
+                if buf.remaining() < len {
+                    return Err(Error::InputTooShort);
+                }
+                let status = buf[..len].iter().any(|&b| b != 0);
+                buf.advance(len);
+                Ok(status.into())
+            }
             _ => Err(Error::UnexpectedLength),
         }
     }
@@ -174,6 +189,31 @@ impl Decodable for Eip658Value {
 mod test {
     use super::*;
 
+    #[test]
+    fn rlp_multi_byte_status() {
+        let buf = [0x82, 0x00, 0x01];
+        let decoded = Eip658Value::decode(&mut &buf[..]);
+        #[cfg(feature = "legacy-status")]
+        assert_eq!(decoded, Ok(Eip658Value::Eip658(true)));
+        #[cfg(not(feature = "legacy-status"))]
+        assert_eq!(decoded, Err(Error::UnexpectedLength));
+
+        let buf = [0x85, 0x00, 0x00, 0x00, 0x00, 0x01];
+        assert_eq!(Eip658Value::decode(&mut &buf[..]), Err(Error::UnexpectedLength));
+    }
+
+    #[cfg(feature = "legacy-status")]
+    #[test]
+    fn rlp_legacy_status() {
+        let buf = [0x84, 0x00, 0x00, 0x00, 0x00, 0x80];
+        let mut slice = &buf[..];
+        assert_eq!(Eip658Value::decode(&mut slice), Ok(Eip658Value::Eip658(false)));
+        assert_eq!(slice, &[0x80]);
+
+        let buf = [0x83, 0x00, 0x01];
+        assert_eq!(Eip658Value::decode(&mut &buf[..]), Err(Error::InputTooShort));
+    }
+
     #[test]
     fn rlp_sanity() {
         let mut buf = Vec::new();
@@ -187,6 +227,12 @@ mod test {
         assert_eq!(Eip658Value::decode(&mut buf.as_slice()), Ok(state));
     }
 
+    #[test]
+    fn default_is_success() {
+        assert_eq!(Eip658Value::default(), Eip658Value::Eip658(true));
+        assert_eq!(crate::Receipt::<()>::default(), crate::Receipt::default_success());
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_sanity() {
@@ -0,0 +1,232 @@
+//! Split storage layout for receipts, keeping logs in a separate table.
+
+use crate::{Eip658Value, Receipt};
+use alloc::vec::Vec;
+use alloy_primitives::Log;
+use core::fmt;
+
+/// The non-log fields of a [`Receipt`], along with the number of logs it contains.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct ReceiptHeader {
+    /// The status or post state of the receipt.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub status: Eip658Value,
+    /// The cumulative gas used.
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    pub cumulative_gas_used: u128,
+    /// The number of logs of the receipt.
+    pub log_count: usize,
+}
+
+/// Splits receipts into their headers and a separate table of logs, each tagged with the index
+/// of the receipt it belongs to.
+///
+/// Use [`join_from_storage`] to reconstruct the receipts.
+pub fn split_for_storage<T>(receipts: Vec<Receipt<T>>) -> (Vec<ReceiptHeader>, Vec<(usize, T)>) {
+    let mut headers = Vec::with_capacity(receipts.len());
+    let mut logs = Vec::with_capacity(receipts.iter().map(|r| r.logs.len()).sum());
+    for (index, receipt) in receipts.into_iter().enumerate() {
+        headers.push(ReceiptHeader {
+            status: receipt.status,
+            cumulative_gas_used: receipt.cumulative_gas_used,
+            log_count: receipt.logs.len(),
+        });
+        logs.extend(receipt.logs.into_iter().map(|log| (index, log)));
+    }
+    (headers, logs)
+}
+
+/// Reconstructs receipts from the headers and tagged logs produced by [`split_for_storage`].
+///
+/// Logs must be ordered by receipt index, and the number of logs tagged with each receipt index
+/// must match the header's log count.
+pub fn join_from_storage<T>(
+    headers: Vec<ReceiptHeader>,
+    logs: Vec<(usize, T)>,
+) -> Result<Vec<Receipt<T>>, SplitReceiptsError> {
+    let mut logs = logs.into_iter().peekable();
+    let mut receipts = Vec::with_capacity(headers.len());
+    for (index, header) in headers.into_iter().enumerate() {
+        // The log count is untrusted, so never reserve more than the remaining logs.
+        let mut receipt_logs = Vec::with_capacity(header.log_count.min(logs.len()));
+        while let Some((_, log)) = logs.next_if(|(i, _)| *i == index) {
+            receipt_logs.push(log);
+        }
+        if receipt_logs.len() != header.log_count {
+            return Err(SplitReceiptsError::LogCountMismatch {
+                receipt: index,
+                expected: header.log_count,
+                got: receipt_logs.len(),
+            });
+        }
+        receipts.push(Receipt {
+            status: header.status,
+            cumulative_gas_used: header.cumulative_gas_used,
+            logs: receipt_logs,
+        });
+    }
+    if let Some((index, _)) = logs.next() {
+        return Err(SplitReceiptsError::UnexpectedLog { receipt: index });
+    }
+    Ok(receipts)
+}
+
+/// Error returned by [`join_from_storage`] when headers and logs are inconsistent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SplitReceiptsError {
+    /// The number of logs for a receipt does not match its header.
+    LogCountMismatch {
+        /// The index of the receipt.
+        receipt: usize,
+        /// The log count of the header.
+        expected: usize,
+        /// The number of logs found for the receipt.
+        got: usize,
+    },
+    /// A log is tagged with a receipt index that is unknown or out of order.
+    UnexpectedLog {
+        /// The receipt index of the log.
+        receipt: usize,
+    },
+}
+
+impl fmt::Display for SplitReceiptsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LogCountMismatch { receipt, expected, got } => {
+                write!(f, "receipt {receipt} expects {expected} logs, got {got}")
+            }
+            Self::UnexpectedLog { receipt } => {
+                write!(f, "unexpected log for receipt {receipt}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for SplitReceiptsError {}
+
+/// Assembles receipts from `(status, cumulative_gas_used, log_count)` headers and a separate
+/// stream of logs, taking the next `log_count` logs for each receipt.
+///
+/// Status bytes must be `0` or `1`. Logs remaining in the stream after the last receipt are not
+/// consumed.
+pub fn assemble_from_counts(
+    headers: &[(u8, u128, usize)],
+    mut logs: impl Iterator<Item = Log>,
+) -> Result<Vec<Receipt<Log>>, AssembleError> {
+    let mut receipts = Vec::with_capacity(headers.len());
+    for (index, &(status, cumulative_gas_used, log_count)) in headers.iter().enumerate() {
+        let status = match status {
+            0 => false,
+            1 => true,
+            status => return Err(AssembleError::InvalidStatus { receipt: index, status }),
+        };
+        let receipt_logs: Vec<_> = logs.by_ref().take(log_count).collect();
+        if receipt_logs.len() != log_count {
+            return Err(AssembleError::LogsExhausted {
+                receipt: index,
+                expected: log_count,
+                got: receipt_logs.len(),
+            });
+        }
+        receipts.push(Receipt { status: status.into(), cumulative_gas_used, logs: receipt_logs });
+    }
+    Ok(receipts)
+}
+
+/// Error returned by [`assemble_from_counts`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AssembleError {
+    /// The status byte of a receipt is neither `0` nor `1`.
+    InvalidStatus {
+        /// The index of the receipt.
+        receipt: usize,
+        /// The status byte.
+        status: u8,
+    },
+    /// The log stream ended before all logs of a receipt were read.
+    LogsExhausted {
+        /// The index of the receipt.
+        receipt: usize,
+        /// The log count of the receipt.
+        expected: usize,
+        /// The number of logs left in the stream.
+        got: usize,
+    },
+}
+
+impl fmt::Display for AssembleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidStatus { receipt, status } => {
+                write!(f, "invalid status {status} for receipt {receipt}")
+            }
+            Self::LogsExhausted { receipt, expected, got } => {
+                write!(f, "receipt {receipt} expects {expected} logs, only {got} left")
+            }
+        }
+    }
+}
+
+impl core::error::Error for AssembleError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::receipt::test_utils::log;
+
+    #[test]
+    fn split_join_roundtrip() {
+        let receipts = vec![
+            Receipt { status: true.into(), cumulative_gas_used: 1, logs: vec![1, 2] },
+            Receipt { status: false.into(), cumulative_gas_used: 2, logs: vec![] },
+            Receipt { status: true.into(), cumulative_gas_used: 3, logs: vec![3] },
+        ];
+
+        let (headers, logs) = split_for_storage(receipts.clone());
+        assert_eq!(headers.iter().map(|h| h.log_count).collect::<Vec<_>>(), vec![2, 0, 1]);
+        assert_eq!(logs, vec![(0, 1), (0, 2), (2, 3)]);
+        assert_eq!(join_from_storage(headers.clone(), logs).unwrap(), receipts);
+
+        assert_eq!(
+            join_from_storage(headers.clone(), vec![(0, 1), (2, 3)]),
+            Err(SplitReceiptsError::LogCountMismatch { receipt: 0, expected: 2, got: 1 })
+        );
+        assert_eq!(
+            join_from_storage(headers, vec![(0, 1), (0, 2), (2, 3), (3, 4)]),
+            Err(SplitReceiptsError::UnexpectedLog { receipt: 3 })
+        );
+
+        let header = ReceiptHeader { log_count: usize::MAX, ..Default::default() };
+        assert_eq!(
+            join_from_storage(vec![header], vec![(0, 1)]),
+            Err(SplitReceiptsError::LogCountMismatch { receipt: 0, expected: usize::MAX, got: 1 })
+        );
+    }
+
+    #[test]
+    fn assemble_from_counts() {
+        let logs = || (1..=3).map(log);
+
+        let receipts = super::assemble_from_counts(&[(1, 1, 2), (0, 2, 0), (1, 3, 1)], logs());
+        assert_eq!(
+            receipts.unwrap(),
+            vec![
+                Receipt { status: true.into(), cumulative_gas_used: 1, logs: vec![log(1), log(2)] },
+                Receipt { status: false.into(), cumulative_gas_used: 2, logs: vec![] },
+                Receipt { status: true.into(), cumulative_gas_used: 3, logs: vec![log(3)] },
+            ]
+        );
+
+        assert_eq!(
+            super::assemble_from_counts(&[(1, 1, 2), (1, 2, 2)], logs()),
+            Err(AssembleError::LogsExhausted { receipt: 1, expected: 2, got: 1 })
+        );
+        assert_eq!(
+            super::assemble_from_counts(&[(1, 1, 0), (2, 2, 0)], logs()),
+            Err(AssembleError::InvalidStatus { receipt: 1, status: 2 })
+        );
+    }
+}
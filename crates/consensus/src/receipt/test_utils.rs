@@ -0,0 +1,28 @@
+//! Fixtures shared by the receipt tests.
+
+use crate::Receipt;
+use alloc::{vec, vec::Vec};
+use alloy_primitives::{Address, Log, LogData, B256};
+
+/// Returns a log without topics and data emitted by `Address::repeat_byte(address)`.
+pub(crate) fn log(address: u8) -> Log {
+    log_with_topics(address, vec![])
+}
+
+/// Returns a log with the given topics and no data emitted by `Address::repeat_byte(address)`.
+pub(crate) fn log_with_topics(address: u8, topics: Vec<B256>) -> Log {
+    Log {
+        address: Address::repeat_byte(address),
+        data: LogData::new_unchecked(topics, Default::default()),
+    }
+}
+
+/// Returns a successful receipt with the given logs and a cumulative gas used of `1`.
+pub(crate) fn receipt<T>(logs: Vec<T>) -> Receipt<T> {
+    Receipt { status: true.into(), cumulative_gas_used: 1, logs }
+}
+
+/// Returns a receipt with `log_count` copies of `log(0)`.
+pub(crate) fn receipt_with(status: bool, cumulative_gas_used: u128, log_count: usize) -> Receipt {
+    Receipt { status: status.into(), cumulative_gas_used, logs: vec![log(0); log_count] }
+}
@@ -0,0 +1,118 @@
+//! Receipt lists framed with a table of contents, for seekable archives.
+//!
+//! The layout is:
+//!
+//! ```text
+//! count: u32 (big endian)
+//! offsets: [u32 (big endian); count]
+//! receipts: concatenated RLP encodings
+//! ```
+//!
+//! Every offset points to the start of a receipt encoding, relative to the start of the
+//! concatenated receipts. This allows decoding a single receipt without touching the others.
+
+use crate::receipt::{ReceiptWithBloom, RlpReceipt};
+use alloc::vec::Vec;
+
+const WORD: usize = core::mem::size_of::<u32>();
+
+/// Encodes the receipts prefixed with a table of per-receipt byte offsets.
+///
+/// Use [`read_at`] to decode a single receipt of the returned blob.
+///
+/// # Panics
+///
+/// Panics if the number of receipts or the offset of any receipt does not fit into a `u32`.
+pub fn encode_with_toc<R: RlpReceipt>(receipts: &[ReceiptWithBloom<R>]) -> Vec<u8> {
+    let mut toc = Vec::with_capacity(WORD * (receipts.len() + 1));
+    let mut body = Vec::new();
+    toc.extend_from_slice(&u32::try_from(receipts.len()).expect("too many receipts").to_be_bytes());
+    for receipt in receipts {
+        toc.extend_from_slice(
+            &u32::try_from(body.len()).expect("receipts too large").to_be_bytes(),
+        );
+        receipt.receipt.rlp_encode_with_bloom(receipt.logs_bloom, &mut body);
+    }
+    toc.extend(body);
+    toc
+}
+
+/// Decodes the receipt at `index` of a blob produced by [`encode_with_toc`].
+///
+/// Only the requested receipt is decoded.
+pub fn read_at<R: RlpReceipt>(blob: &[u8], index: usize) -> alloy_rlp::Result<ReceiptWithBloom<R>> {
+    // The count and offsets are untrusted, so offsets into the blob that overflow `usize` are
+    // treated as out of bounds.
+    let word_offset = |at: usize| at.checked_mul(WORD).ok_or(alloy_rlp::Error::InputTooShort);
+    let word = |at: usize| -> alloy_rlp::Result<usize> {
+        let end = word_offset(at.checked_add(1).ok_or(alloy_rlp::Error::InputTooShort)?)?;
+        let bytes = blob.get(end - WORD..end).ok_or(alloy_rlp::Error::InputTooShort)?;
+        Ok(u32::from_be_bytes(bytes.try_into().unwrap()) as usize)
+    };
+
+    let count = word(0)?;
+    if index >= count {
+        return Err(alloy_rlp::Error::Custom("receipt index out of bounds"));
+    }
+    let body_start = count.checked_add(1).ok_or(alloy_rlp::Error::InputTooShort)?;
+    let body = blob.get(word_offset(body_start)?..).ok_or(alloy_rlp::Error::InputTooShort)?;
+    let start = word(index + 1)?;
+    let end = if index + 1 < count { word(index + 2)? } else { body.len() };
+
+    let mut buf = body.get(start..end).ok_or(alloy_rlp::Error::InputTooShort)?;
+    let receipt = R::rlp_decode_with_bloom(&mut buf)?;
+    if !buf.is_empty() {
+        return Err(alloy_rlp::Error::UnexpectedLength);
+    }
+    Ok(receipt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Receipt;
+    use alloy_primitives::{Address, Log};
+
+    #[test]
+    fn toc_roundtrip() {
+        let receipts: Vec<ReceiptWithBloom<Receipt>> = (0..4)
+            .map(|i| {
+                Receipt {
+                    status: (i % 2 == 0).into(),
+                    cumulative_gas_used: 21_000 * (i as u128 + 1),
+                    logs: (0..i)
+                        .map(|_| {
+                            Log::new_unchecked(Address::repeat_byte(i), vec![], Default::default())
+                        })
+                        .collect(),
+                }
+                .with_bloom()
+            })
+            .collect();
+
+        let blob = encode_with_toc(&receipts);
+        for index in [2, 0, 3, 1] {
+            assert_eq!(read_at::<Receipt>(&blob, index).unwrap(), receipts[index]);
+        }
+        assert_eq!(
+            read_at::<Receipt>(&blob, 4),
+            Err(alloy_rlp::Error::Custom("receipt index out of bounds"))
+        );
+        assert_eq!(
+            read_at::<Receipt>(&blob[..blob.len() - 1], 3),
+            Err(alloy_rlp::Error::InputTooShort)
+        );
+        assert_eq!(
+            read_at::<Receipt>(&encode_with_toc::<Receipt>(&[]), 0),
+            Err(alloy_rlp::Error::Custom("receipt index out of bounds"))
+        );
+
+        // A malformed count pointing past the end of the blob.
+        let mut blob = blob;
+        blob[..WORD].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert_eq!(
+            read_at::<Receipt>(&blob, (u32::MAX - 1) as usize),
+            Err(alloy_rlp::Error::InputTooShort)
+        );
+    }
+}
@@ -0,0 +1,206 @@
+//! Helpers operating on the receipts of a single block.
+
+use crate::receipt::{
+    receipts::{aggregate_bloom, block_gas_used, gas_used_per_tx},
+    TxReceipt,
+};
+use alloc::vec::Vec;
+use alloy_primitives::Bloom;
+
+/// Returns the XOR of the aggregated blooms of two sets of receipts.
+///
+/// Set bits are present in the bloom of exactly one of the sets, so a zero result means both sets
+/// have the same aggregated bloom. This is a quick signal for whether the log contents of e.g. two
+/// executions of the same block diverged.
+pub fn bloom_xor<R: TxReceipt>(a: &[R], b: &[R]) -> Bloom {
+    aggregate_bloom(a) ^ aggregate_bloom(b)
+}
+
+/// Validates that the aggregated bloom of the receipts of a block equals the bloom of its header.
+///
+/// Returns the computed bloom on mismatch.
+#[allow(clippy::result_large_err)]
+pub fn validate_header_bloom<R: TxReceipt>(
+    receipts: &[R],
+    header_bloom: Bloom,
+) -> Result<(), Bloom> {
+    let bloom = aggregate_bloom(receipts);
+    if bloom != header_bloom {
+        return Err(bloom);
+    }
+    Ok(())
+}
+
+/// Validates that the total gas used by the receipts of a block, i.e. the cumulative gas used of
+/// the last receipt, does not exceed the block gas limit.
+///
+/// Returns the total gas used on violation. A block without receipts is always valid.
+pub fn validate_block_gas_limit<R: TxReceipt>(receipts: &[R], gas_limit: u128) -> Result<(), u128> {
+    let gas_used = block_gas_used(receipts);
+    if gas_used > gas_limit {
+        return Err(gas_used);
+    }
+    Ok(())
+}
+
+/// The differences in gas accounting between two executions of the same block, see
+/// [`gas_divergence`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GasDivergence {
+    /// The index of each transaction whose gas used differs, along with the gas used in the first
+    /// and second execution.
+    pub txs: Vec<(usize, u128, u128)>,
+    /// The number of receipts of the first and second execution, if they differ.
+    pub len_mismatch: Option<(usize, usize)>,
+}
+
+impl GasDivergence {
+    /// Returns `true` if both executions agree on the gas used of every transaction.
+    pub fn is_empty(&self) -> bool {
+        self.txs.is_empty() && self.len_mismatch.is_none()
+    }
+}
+
+/// Compares the gas used by each transaction of two executions of the same block.
+///
+/// Transactions are compared up to the length of the shorter slice. A length mismatch is reported
+/// in [`GasDivergence::len_mismatch`].
+pub fn gas_divergence<R: TxReceipt>(a: &[R], b: &[R]) -> GasDivergence {
+    let txs = gas_used_per_tx(a)
+        .zip(gas_used_per_tx(b))
+        .enumerate()
+        .filter(|(_, (a, b))| a != b)
+        .map(|(index, (a, b))| (index, a, b))
+        .collect();
+    let len_mismatch = (a.len() != b.len()).then_some((a.len(), b.len()));
+    GasDivergence { txs, len_mismatch }
+}
+
+/// Verifies that the receipts of a block are in transaction order, i.e. that the cumulative gas
+/// used strictly increases from one receipt to the next.
+///
+/// Since every transaction consumes gas, equal consecutive values, or a zero value for the first
+/// receipt, are treated as out of order. Set `allow_equal` to only require the cumulative gas used
+/// to be non-decreasing.
+///
+/// Returns the index of the first receipt that is out of order.
+pub fn verify_order<R: TxReceipt>(receipts: &[R], allow_equal: bool) -> Result<(), usize> {
+    let mut prev = None;
+    for (index, receipt) in receipts.iter().enumerate() {
+        let cumulative = receipt.cumulative_gas_used();
+        let in_order = match prev {
+            Some(prev) if allow_equal => cumulative >= prev,
+            Some(prev) => cumulative > prev,
+            None => allow_equal || cumulative > 0,
+        };
+        if !in_order {
+            return Err(index);
+        }
+        prev = Some(cumulative);
+    }
+    Ok(())
+}
+
+/// Sorts receipts that were assembled out of order by their transaction index, restoring the
+/// canonical block order.
+///
+/// Use [`strip_tx_indices`] to drop the indices afterwards.
+///
+/// ## Note
+///
+/// The cumulative gas used of a receipt depends on its position in the block. If the receipts were
+/// assembled with cumulative values computed for a different order, those values are invalid
+/// after sorting and must be recomputed by the caller.
+pub fn sort_by_tx_index<R>(receipts: &mut [(u32, R)]) {
+    receipts.sort_by_key(|(index, _)| *index);
+}
+
+/// Drops the transaction indices of receipts sorted with [`sort_by_tx_index`].
+pub fn strip_tx_indices<R>(receipts: Vec<(u32, R)>) -> Vec<R> {
+    receipts.into_iter().map(|(_, receipt)| receipt).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Receipt;
+    use alloy_primitives::{Address, Log};
+
+    #[test]
+    fn header_bloom() {
+        let receipts: Vec<_> = (1..=2)
+            .map(|byte| Receipt {
+                status: true.into(),
+                cumulative_gas_used: 21_000 * byte as u128,
+                logs: vec![Log::new_unchecked(
+                    Address::repeat_byte(byte),
+                    vec![],
+                    Default::default(),
+                )],
+            })
+            .collect();
+        let bloom = receipts[0].bloom_slow() | receipts[1].bloom_slow();
+
+        assert_eq!(validate_header_bloom(&receipts, bloom), Ok(()));
+        assert_eq!(validate_header_bloom(&receipts, Bloom::ZERO), Err(bloom));
+        assert_eq!(validate_header_bloom::<Receipt>(&[], Bloom::ZERO), Ok(()));
+    }
+
+    #[test]
+    fn block_gas_limit() {
+        let receipt = |cumulative_gas_used| Receipt::<Log> {
+            status: true.into(),
+            cumulative_gas_used,
+            logs: vec![],
+        };
+        let receipts = [receipt(21_000), receipt(60_000)];
+
+        assert_eq!(validate_block_gas_limit(&receipts, 60_000), Ok(()));
+        assert_eq!(validate_block_gas_limit(&receipts, 59_999), Err(60_000));
+        assert_eq!(validate_block_gas_limit::<Receipt>(&[], 0), Ok(()));
+    }
+
+    #[test]
+    fn divergence() {
+        let receipt = |cumulative_gas_used| Receipt::<Log> {
+            status: true.into(),
+            cumulative_gas_used,
+            logs: vec![],
+        };
+        let a = [receipt(21_000), receipt(50_000), receipt(80_000)];
+        let b = [receipt(21_000), receipt(55_000), receipt(85_000), receipt(90_000)];
+
+        assert_eq!(
+            gas_divergence(&a, &b),
+            GasDivergence { txs: vec![(1, 29_000, 34_000)], len_mismatch: Some((3, 4)) }
+        );
+        assert!(gas_divergence(&a, &a).is_empty());
+    }
+
+    #[test]
+    fn order() {
+        let receipts = |gas: &[u128]| -> Vec<Receipt> {
+            gas.iter()
+                .map(|&cumulative_gas_used| Receipt {
+                    status: true.into(),
+                    cumulative_gas_used,
+                    logs: vec![],
+                })
+                .collect()
+        };
+
+        assert_eq!(verify_order(&receipts(&[1, 2, 3]), false), Ok(()));
+        assert_eq!(verify_order(&receipts(&[1, 2, 2, 1]), false), Err(2));
+        assert_eq!(verify_order(&receipts(&[1, 2, 2, 1]), true), Err(3));
+        assert_eq!(verify_order(&receipts(&[0, 1]), false), Err(0));
+        assert_eq!(verify_order(&receipts(&[0, 0]), true), Ok(()));
+        assert_eq!(verify_order(&receipts(&[]), false), Ok(()));
+    }
+
+    #[test]
+    fn sort_and_strip() {
+        let mut receipts = vec![(2, 'c'), (0, 'a'), (1, 'b')];
+        sort_by_tx_index(&mut receipts);
+        assert_eq!(strip_tx_indices(receipts), vec!['a', 'b', 'c']);
+    }
+}
@@ -0,0 +1,163 @@
+//! Versioned [`Receipts`] collections for persistence.
+
+use crate::Receipts;
+use core::fmt;
+
+/// A [`Receipts`] collection tagged with the version of its schema, e.g. for persisting receipts
+/// across format migrations.
+///
+/// Deserializing a collection with an unknown version, i.e. `0` or newer than
+/// [`VersionedReceipts::CURRENT_VERSION`], fails with a [`MigrationError`]. Older versions are
+/// accepted and converted by [`VersionedReceipts::migrate`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawVersionedReceipts<T>"))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: serde::Serialize", deserialize = "T: serde::Deserialize<'de>"))
+)]
+pub struct VersionedReceipts<T> {
+    /// The schema version of the receipts.
+    pub version: u8,
+    /// The receipts.
+    pub receipts: Receipts<T>,
+}
+
+impl<T> VersionedReceipts<T> {
+    /// The current schema version.
+    pub const CURRENT_VERSION: u8 = 1;
+
+    /// Returns whether `version` is a known schema version, i.e. between `1` and
+    /// [`VersionedReceipts::CURRENT_VERSION`].
+    pub const fn is_known_version(version: u8) -> bool {
+        version != 0 && version <= Self::CURRENT_VERSION
+    }
+
+    /// Tags the receipts with the current schema version.
+    pub const fn new(receipts: Receipts<T>) -> Self {
+        Self { version: Self::CURRENT_VERSION, receipts }
+    }
+
+    /// Migrates the receipts to the current schema version.
+    ///
+    /// This is a no-op for the current version, older versions are to be converted here once the
+    /// schema changes.
+    pub fn migrate(self) -> Result<Receipts<T>, MigrationError> {
+        match self.version {
+            Self::CURRENT_VERSION => Ok(self.receipts),
+            version => Err(MigrationError::UnsupportedVersion(version)),
+        }
+    }
+}
+
+impl<T> From<Receipts<T>> for VersionedReceipts<T> {
+    fn from(receipts: Receipts<T>) -> Self {
+        Self::new(receipts)
+    }
+}
+
+/// Deserialization target of [`VersionedReceipts`] before the version is checked.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(bound = "T: serde::Deserialize<'de>")]
+struct RawVersionedReceipts<T> {
+    version: u8,
+    receipts: Receipts<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> TryFrom<RawVersionedReceipts<T>> for VersionedReceipts<T> {
+    type Error = MigrationError;
+
+    fn try_from(raw: RawVersionedReceipts<T>) -> Result<Self, Self::Error> {
+        if !Self::is_known_version(raw.version) {
+            return Err(MigrationError::UnsupportedVersion(raw.version));
+        }
+        Ok(Self { version: raw.version, receipts: raw.receipts })
+    }
+}
+
+/// Error returned when migrating [`VersionedReceipts`] fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MigrationError {
+    /// The schema version is not supported.
+    UnsupportedVersion(u8),
+}
+
+impl fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "unsupported receipts version {version}, expected 1 to {}",
+                VersionedReceipts::<()>::CURRENT_VERSION
+            ),
+        }
+    }
+}
+
+impl core::error::Error for MigrationError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Receipt;
+
+    #[test]
+    fn migrate() {
+        let receipts = Receipts { receipt_vec: vec![vec![Receipt::<()>::default()]] };
+        let versioned = VersionedReceipts::new(receipts.clone());
+        assert_eq!(versioned.version, VersionedReceipts::<()>::CURRENT_VERSION);
+        assert_eq!(versioned.migrate(), Ok(receipts.clone()));
+
+        let unsupported = VersionedReceipts { version: 0, receipts };
+        assert_eq!(unsupported.migrate(), Err(MigrationError::UnsupportedVersion(0)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_versioned_receipts() {
+        let receipts = Receipts { receipt_vec: vec![vec![Receipt::<()>::default()], vec![]] };
+        let versioned = VersionedReceipts::from(receipts);
+        let json = serde_json::to_string(&versioned).unwrap();
+        assert_eq!(
+            serde_json::from_str::<VersionedReceipts<Receipt<()>>>(&json).unwrap(),
+            versioned
+        );
+
+        let err = serde_json::from_str::<VersionedReceipts<Receipt<()>>>(&json.replacen(
+            "\"version\":1",
+            "\"version\":0",
+            1,
+        ))
+        .unwrap_err();
+        assert!(err.to_string().contains("unsupported receipts version 0"), "{err}");
+
+        let newer = VersionedReceipts::<()>::CURRENT_VERSION + 1;
+        let err = serde_json::from_str::<VersionedReceipts<Receipt<()>>>(&json.replacen(
+            "\"version\":1",
+            &format!("\"version\":{newer}"),
+            1,
+        ))
+        .unwrap_err();
+        assert!(
+            err.to_string().contains(&format!("unsupported receipts version {newer}")),
+            "{err}"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_migrate_known_versions() {
+        // Every known version, including older ones, deserializes and is migrated to the current
+        // schema.
+        let receipts = Receipts { receipt_vec: vec![vec![Receipt::<()>::default()], vec![]] };
+        let json = serde_json::to_string(&VersionedReceipts::new(receipts.clone())).unwrap();
+        for version in 1..=VersionedReceipts::<()>::CURRENT_VERSION {
+            let json = json.replacen("\"version\":1", &format!("\"version\":{version}"), 1);
+            let versioned = serde_json::from_str::<VersionedReceipts<Receipt<()>>>(&json).unwrap();
+            assert_eq!(versioned.version, version);
+            assert_eq!(versioned.migrate(), Ok(receipts.clone()));
+        }
+    }
+}
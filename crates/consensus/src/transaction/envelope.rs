@@ -71,7 +71,7 @@ impl PartialEq<TxType> for u8 {
 #[cfg(any(test, feature = "arbitrary"))]
 impl arbitrary::Arbitrary<'_> for TxType {
     fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
-        Ok(u.int_in_range(0u8..=3)?.try_into().unwrap())
+        Ok(u.int_in_range(0u8..=4)?.try_into().unwrap())
     }
 }
 
@@ -766,6 +766,15 @@ mod tests {
         assert_eq!(TxType::Eip4844, TxType::Eip4844 as u8);
     }
 
+    #[test]
+    fn tx_type_u8_roundtrip() {
+        for ty in 0u8..=4 {
+            let tx_type = TxType::try_from(ty).unwrap();
+            assert_eq!(u8::from(tx_type), ty);
+        }
+        assert!(matches!(TxType::try_from(5u8), Err(Eip2718Error::UnexpectedType(5))));
+    }
+
     #[test]
     #[cfg(feature = "k256")]
     // Test vector from https://etherscan.io/tx/0xce4dc6d7a7549a98ee3b071b67e970879ff51b5b95d1c340bacd80fa1e1aab31